@@ -0,0 +1,91 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Generates the tables of the `hanja` and `loanwords` features from data/
+fn main() {
+  println!("cargo:rerun-if-changed=build.rs");
+  println!("cargo:rerun-if-changed=data/hanja.txt");
+  println!("cargo:rerun-if-changed=data/loanwords.txt");
+
+  if env::var_os("CARGO_FEATURE_HANJA").is_some() {
+    hanja();
+  }
+
+  if env::var_os("CARGO_FEATURE_LOANWORDS").is_some() {
+    loanwords();
+  }
+}
+
+// Generates the table of Hanja readings from data/hanja.txt
+fn hanja() {
+  let data = fs::read_to_string("data/hanja.txt").expect("failed to read data/hanja.txt");
+  let mut entries: Vec<(char, char)> = lines(&data)
+    .map(|line| {
+      let mut chars = line.chars();
+
+      match (chars.next(), chars.next(), chars.next(), chars.next()) {
+        (Some(hanja), Some('\t'), Some(reading), None) => (hanja, reading),
+        _ => panic!("malformed line in data/hanja.txt: {}", line)
+      }
+    })
+    .collect();
+
+  entries.sort();
+  entries.dedup_by_key(|&mut (hanja, _)| hanja);
+
+  let table: String = entries
+    .iter()
+    .map(|(hanja, reading)| format!("  ({:?}, {:?}),\n", hanja, reading))
+    .collect();
+  let source = format!(
+    "static HANJA: [(char, char); {}] = [\n{}];\n",
+    entries.len(),
+    table
+  );
+
+  write("hanja.rs", source);
+}
+
+// Generates the table of loanwords and their readings from data/loanwords.txt
+fn loanwords() {
+  let data = fs::read_to_string("data/loanwords.txt").expect("failed to read data/loanwords.txt");
+  let mut entries: Vec<(&str, &str)> = lines(&data)
+    .map(|line| match line.split_once('\t') {
+      Some((word, reading))
+        if !word.is_empty()
+          && word.bytes().all(|b| b.is_ascii_lowercase())
+          && !reading.is_empty()
+          && reading.chars().all(|c| ('가'..='힣').contains(&c)) =>
+      {
+        (word, reading)
+      }
+      _ => panic!("malformed line in data/loanwords.txt: {}", line)
+    })
+    .collect();
+
+  entries.sort();
+  entries.dedup_by_key(|&mut (word, _)| word);
+
+  let table: String = entries
+    .iter()
+    .map(|(word, reading)| format!("  ({:?}, {:?}),\n", word, reading))
+    .collect();
+  let source = format!(
+    "static LOANWORDS: [(&str, &str); {}] = [\n{}];\n",
+    entries.len(),
+    table
+  );
+
+  write("loanwords.rs", source);
+}
+
+// Lines of a data file, without blank lines and comments
+fn lines(data: &str) -> impl Iterator<Item = &str> {
+  data.lines().filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn write(file: &str, source: String) {
+  let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join(file);
+  fs::write(out, source).expect("failed to write a generated table");
+}
@@ -0,0 +1,129 @@
+//! Conjugations of the copula 이다 attached to a noun.
+//!
+//! Unlike [`Josa`](../enum.Josa.html), the open syllable form of a copula ending
+//! is not always the closed one without `이`.
+//! `이` is either dropped (이고요/고요) or contracted with the following vowel (이었다/였다, 이에요/예요),
+//! so [`conjugate`](fn.conjugate.html) returns the whole conjugated word instead of the ending only.
+//!
+//! ```
+//! use josa::copula::{conjugate, CopulaEnding};
+//! # use josa::Error;
+//!
+//! assert_eq!(conjugate("학생", CopulaEnding::Ieotseumnida)?, "학생이었습니다");
+//! assert_eq!(conjugate("의사", CopulaEnding::Ieotseumnida)?, "의사였습니다");
+//! # Ok::<(), Error>(())
+//! ```
+
+use crate::{JongseongKind, Result, Selector};
+
+/// Enum of copula endings that [`conjugate`](fn.conjugate.html) attaches to a noun.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopulaEnding {
+  /// 이다/다
+  Ida,
+  /// 입니다
+  Imnida,
+  /// 입니까
+  Imnikka,
+  /// 이에요/예요
+  Ieyo,
+  /// 이에요?/예요?
+  IeyoQuestion,
+  /// 이었다/였다
+  Ieotda,
+  /// 이었습니다/였습니다
+  Ieotseumnida,
+  /// 이었습니까/였습니까
+  Ieotseumnikka,
+  /// 이었어요/였어요
+  Ieosseoyo,
+  /// 이었고/였고
+  Ieotgo,
+  /// 이고요/고요
+  Igoyo,
+  /// 이고/고
+  Igo,
+  /// 이지요/지요
+  Ijiyo,
+  /// 인지
+  Inji,
+  /// 인데
+  Inde
+}
+
+impl CopulaEnding {
+  fn closed(self) -> &'static str {
+    match self {
+      CopulaEnding::Ida => "이다",
+      CopulaEnding::Imnida => "입니다",
+      CopulaEnding::Imnikka => "입니까",
+      CopulaEnding::Ieyo => "이에요",
+      CopulaEnding::IeyoQuestion => "이에요?",
+      CopulaEnding::Ieotda => "이었다",
+      CopulaEnding::Ieotseumnida => "이었습니다",
+      CopulaEnding::Ieotseumnikka => "이었습니까",
+      CopulaEnding::Ieosseoyo => "이었어요",
+      CopulaEnding::Ieotgo => "이었고",
+      CopulaEnding::Igoyo => "이고요",
+      CopulaEnding::Igo => "이고",
+      CopulaEnding::Ijiyo => "이지요",
+      CopulaEnding::Inji => "인지",
+      CopulaEnding::Inde => "인데"
+    }
+  }
+
+  fn open(self) -> &'static str {
+    match self {
+      CopulaEnding::Ida => "다",
+      CopulaEnding::Imnida => "입니다",
+      CopulaEnding::Imnikka => "입니까",
+      CopulaEnding::Ieyo => "예요",
+      CopulaEnding::IeyoQuestion => "예요?",
+      CopulaEnding::Ieotda => "였다",
+      CopulaEnding::Ieotseumnida => "였습니다",
+      CopulaEnding::Ieotseumnikka => "였습니까",
+      CopulaEnding::Ieosseoyo => "였어요",
+      CopulaEnding::Ieotgo => "였고",
+      CopulaEnding::Igoyo => "고요",
+      CopulaEnding::Igo => "고",
+      CopulaEnding::Ijiyo => "지요",
+      CopulaEnding::Inji => "인지",
+      CopulaEnding::Inde => "인데"
+    }
+  }
+}
+
+/// Conjugate the copula 이다 with a given ending after a noun.
+///
+/// Endings that keep `이` after an open syllable in standard usage,
+/// such as 입니다 and 인지, are attached as is.
+/// Like [`push_josa`](../trait.JosaExt.html#tymethod.push_josa), the ending goes before trailing punctuation,
+/// and it goes before trailing whitespace as well, e.g. 의사. as 의사다.
+///
+/// # Errors
+/// If given noun is an empty string
+/// or the last character is not a Hangul Syllable,
+/// it returns [`Error`](../enum.Error.html).
+///
+/// # Example
+/// ```
+/// use josa::copula::{conjugate, CopulaEnding};
+/// # use josa::Error;
+///
+/// assert_eq!(conjugate("고양이", CopulaEnding::Ieyo)?, "고양이예요");
+/// assert_eq!(conjugate("책", CopulaEnding::Ieyo)?, "책이에요");
+/// assert_eq!(conjugate("나무", CopulaEnding::Imnida)?, "나무입니다");
+/// # Ok::<(), Error>(())
+/// ```
+pub fn conjugate(noun: &str, ending: CopulaEnding) -> Result<String> {
+  let selector = Selector::new();
+  let ending = match selector.classify(noun)? {
+    JongseongKind::Open => ending.open(),
+    JongseongKind::Rieul | JongseongKind::Closed => ending.closed()
+  };
+
+  let mut word = noun.to_owned();
+  word.insert_str(selector.copula_point(noun), ending);
+
+  Ok(word)
+}
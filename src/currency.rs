@@ -0,0 +1,60 @@
+use crate::{number, unit, JongseongKind, Result};
+
+// Currency symbols and ISO 4217 codes, and the jongseong of the Korean name of their currency
+static CURRENCIES: [(&str, JongseongKind); 28] = [
+  ("$", JongseongKind::Open),     // 달러
+  ("€", JongseongKind::Open),     // 유로
+  ("₩", JongseongKind::Closed),   // 원
+  ("¥", JongseongKind::Closed),   // 엔
+  ("£", JongseongKind::Open),     // 파운드
+  ("¢", JongseongKind::Open),     // 센트
+  ("₹", JongseongKind::Open),     // 루피
+  ("₽", JongseongKind::Rieul),    // 루블
+  ("฿", JongseongKind::Open),     // 바트
+  ("₫", JongseongKind::Closed),   // 동
+  ("₱", JongseongKind::Open),     // 페소
+  ("₺", JongseongKind::Open),     // 리라
+  ("₿", JongseongKind::Closed),   // 비트코인
+  ("USD", JongseongKind::Open),   // 달러
+  ("EUR", JongseongKind::Open),   // 유로
+  ("KRW", JongseongKind::Closed), // 원
+  ("JPY", JongseongKind::Closed), // 엔
+  ("CNY", JongseongKind::Closed), // 위안
+  ("GBP", JongseongKind::Open),   // 파운드
+  ("CHF", JongseongKind::Closed), // 프랑
+  ("CAD", JongseongKind::Open),   // 달러
+  ("AUD", JongseongKind::Open),   // 달러
+  ("HKD", JongseongKind::Open),   // 달러
+  ("SGD", JongseongKind::Open),   // 달러
+  ("INR", JongseongKind::Open),   // 루피
+  ("RUB", JongseongKind::Rieul),  // 루블
+  ("VND", JongseongKind::Closed), // 동
+  ("BTC", JongseongKind::Closed)  // 비트코인
+];
+
+// Whether a string ends with a currency, not as a part of a longer word such as BUSD
+fn ends_with_currency(noun: &str, currency: &str) -> bool {
+  match noun.strip_suffix(currency) {
+    Some(rest) => !matches!(rest.chars().last(), Some(c) if c.is_ascii_alphabetic()),
+    None => false
+  }
+}
+
+// Classifies a price by the Korean name of its currency, which is read after the amount,
+// e.g. $5 and 5 USD as 오 달러, and ₩1,000 as 천 원.
+// Returns `None` if the string does not end with a price in a known currency.
+pub(crate) fn classify(noun: &str) -> Option<Result<JongseongKind>> {
+  let start = number::start(noun);
+  let prefix = noun[..start].strip_suffix(' ').unwrap_or(&noun[..start]);
+
+  CURRENCIES
+    .iter()
+    .find(|&&(currency, _)| {
+      if start < noun.len() {
+        ends_with_currency(prefix, currency)
+      } else {
+        unit::follows_number(noun, currency)
+      }
+    })
+    .map(|&(_, kind)| Ok(kind))
+}
@@ -4,10 +4,15 @@ use hangul::ParseSyllableError;
 
 /// Error type for appending josa to a string. Occurs when the string is empty, 
 /// or does not end with Hangul Syllable.
+/// [`select_html`](fn.select_html.html) also fails on malformed HTML.
+///
+/// More variants may be added in minor versions, so matching on it needs a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
   EmptyStr,
   ParseSyllable(char),
+  MalformedHtml,
 }
 
 impl fmt::Display for Error {
@@ -18,6 +23,7 @@ impl fmt::Display for Error {
       match self {
         Error::EmptyStr => "Empty string given to josa selector".to_owned(),
         Error::ParseSyllable(c) => format!("{} is not a Hangul Syllable", c),
+        Error::MalformedHtml => "Malformed HTML given to josa selector".to_owned(),
       }
     )
   }
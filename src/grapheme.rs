@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+// Hangul conjoining jamo, which a grapheme cluster of decomposed Hangul is made of
+fn is_conjoining(c: char) -> bool {
+  matches!(c, '\u{1100}'..='\u{11ff}' | '\u{a960}'..='\u{a97f}' | '\u{d7b0}'..='\u{d7ff}')
+}
+
+// Replaces the last extended grapheme cluster of a string with its base scalar,
+// e.g. 각́ (각, U+0301) as 각 and 1️⃣ as 1.
+// Conjoining jamo after the base, as in decomposed Hangul, are kept so that they can be composed.
+pub(crate) fn base(noun: &str) -> Cow<'_, str> {
+  let (start, cluster) = match noun.grapheme_indices(true).next_back() {
+    Some(last) => last,
+    None => return Cow::Borrowed(noun)
+  };
+
+  let end = cluster
+    .char_indices()
+    .skip(1)
+    .find(|&(_, c)| !is_conjoining(c))
+    .map_or(cluster.len(), |(i, _)| i);
+
+  if end == cluster.len() {
+    Cow::Borrowed(noun)
+  } else {
+    Cow::Owned(format!("{}{}", &noun[..start], &cluster[..end]))
+  }
+}
@@ -0,0 +1,41 @@
+use crate::JongseongKind;
+
+// Greek letters and the jongseong of their Korean names.
+// Final sigma (ς) is read as sigma.
+const LETTERS: [(char, char, JongseongKind); 24] = [
+  ('Α', 'α', JongseongKind::Open),   // 알파
+  ('Β', 'β', JongseongKind::Open),   // 베타
+  ('Γ', 'γ', JongseongKind::Open),   // 감마
+  ('Δ', 'δ', JongseongKind::Open),   // 델타
+  ('Ε', 'ε', JongseongKind::Closed), // 엡실론
+  ('Ζ', 'ζ', JongseongKind::Open),   // 제타
+  ('Η', 'η', JongseongKind::Open),   // 에타
+  ('Θ', 'θ', JongseongKind::Open),   // 세타
+  ('Ι', 'ι', JongseongKind::Open),   // 요타
+  ('Κ', 'κ', JongseongKind::Open),   // 카파
+  ('Λ', 'λ', JongseongKind::Open),   // 람다
+  ('Μ', 'μ', JongseongKind::Open),   // 뮤
+  ('Ν', 'ν', JongseongKind::Open),   // 뉴
+  ('Ξ', 'ξ', JongseongKind::Open),   // 크시
+  ('Ο', 'ο', JongseongKind::Closed), // 오미크론
+  ('Π', 'π', JongseongKind::Open),   // 파이
+  ('Ρ', 'ρ', JongseongKind::Open),   // 로
+  ('Σ', 'σ', JongseongKind::Open),   // 시그마
+  ('Τ', 'τ', JongseongKind::Open),   // 타우
+  ('Υ', 'υ', JongseongKind::Closed), // 입실론
+  ('Φ', 'φ', JongseongKind::Open),   // 파이
+  ('Χ', 'χ', JongseongKind::Open),   // 카이
+  ('Ψ', 'ψ', JongseongKind::Open),   // 프사이
+  ('Ω', 'ω', JongseongKind::Open)    // 오메가
+];
+
+// Classifies the Korean name of a Greek letter, e.g. β as 베타.
+// Returns `None` if given character is not a Greek letter.
+pub(crate) fn letter(c: char) -> Option<JongseongKind> {
+  let c = if c == 'ς' { 'σ' } else { c };
+
+  LETTERS
+    .iter()
+    .find(|&&(upper, lower, _)| c == upper || c == lower)
+    .map(|&(_, _, kind)| kind)
+}
@@ -0,0 +1,12 @@
+// Table of Hanja and their Korean readings, sorted by Hanja, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/hanja.rs"));
+
+// Returns the Korean reading of a Hanja in KS X 1001, e.g. 國 as 국.
+// A Hanja with several readings is read by its most used one, e.g. 金 as 금.
+// Returns `None` if given character is not in the table.
+pub(crate) fn reading(c: char) -> Option<char> {
+  HANJA
+    .binary_search_by_key(&c, |&(hanja, _)| hanja)
+    .ok()
+    .map(|i| HANJA[i].1)
+}
@@ -0,0 +1,76 @@
+use crate::{Error, Result};
+
+// Longest entity reference to look for, such as &#x10FFFF;
+const MAX_ENTITY_LEN: usize = 32;
+
+// Extracts the text of HTML, removing tags and comments and decoding entity references,
+// e.g. <b>고양이</b>&nbsp; as "고양이 ".
+// A bare & that doesn't start a known entity reference is kept as is,
+// and so is a bare < that doesn't start a tag, as in a < b.
+pub(crate) fn text(html: &str) -> Result<String> {
+  let mut text = String::new();
+  let mut rest = html;
+
+  while let Some(i) = rest.find(['<', '&']) {
+    text.push_str(&rest[..i]);
+    rest = &rest[i..];
+
+    if rest.starts_with("<!--") {
+      let end = rest.find("-->").ok_or(Error::MalformedHtml)?;
+      rest = &rest[end + "-->".len()..];
+    } else if rest.starts_with('<') && is_tag(rest) {
+      let end = rest.find('>').ok_or(Error::MalformedHtml)?;
+      rest = &rest[end + 1..];
+    } else if rest.starts_with('<') {
+      text.push('<');
+      rest = &rest[1..];
+    } else {
+      let (c, len) = entity(rest)?;
+      text.push(c);
+      rest = &rest[len..];
+    }
+  }
+
+  text.push_str(rest);
+
+  Ok(text)
+}
+
+// Returns whether a < at the start of a string starts a tag, a declaration or a processing instruction,
+// i.e. whether a letter, /, ! or ? follows it
+fn is_tag(s: &str) -> bool {
+  matches!(s[1..].chars().next(), Some(c) if c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?'))
+}
+
+// Decodes an entity reference at the start of a string, such as &#44592; or &nbsp;,
+// and returns the character along with the length of the reference.
+// No-break space is decoded as a space, since it separates words just like a space.
+fn entity(s: &str) -> Result<(char, usize)> {
+  let end = match s.find(';') {
+    Some(end) if end <= MAX_ENTITY_LEN => end,
+    _ => return Ok(('&', 1))
+  };
+  let name = &s[1..end];
+
+  let c = match name.strip_prefix('#') {
+    Some(number) => {
+      let code = match number.strip_prefix(|c| c == 'x' || c == 'X') {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => number.parse()
+      };
+
+      code.ok().and_then(char::from_u32).ok_or(Error::MalformedHtml)?
+    }
+    None => match name {
+      "nbsp" => ' ',
+      "amp" => '&',
+      "lt" => '<',
+      "gt" => '>',
+      "quot" => '"',
+      "apos" => '\'',
+      _ => return Ok(('&', 1))
+    }
+  };
+
+  Ok((c, end + 1))
+}
@@ -0,0 +1,152 @@
+use std::convert::TryFrom;
+
+use crate::JongseongKind;
+
+const SYLLABLE_BASE: u32 = 0xac00;
+const CHOSEONG_BASE: u32 = 0x1100;
+const JUNGSEONG_BASE: u32 = 0x1161;
+const JONGSEONG_BASE: u32 = 0x11a7;
+const CHOSEONG_COUNT: u32 = 19;
+const JUNGSEONG_COUNT: u32 = 21;
+const JONGSEONG_COUNT: u32 = 28;
+
+// Index of a modern conjoining choseong, e.g. U+1100 (ᄀ) as 0
+fn choseong(c: char) -> Option<u32> {
+  (c as u32).checked_sub(CHOSEONG_BASE).filter(|&i| i < CHOSEONG_COUNT)
+}
+
+// Index of a modern conjoining jungseong, e.g. U+1161 (ᅡ) as 0
+fn jungseong(c: char) -> Option<u32> {
+  (c as u32).checked_sub(JUNGSEONG_BASE).filter(|&i| i < JUNGSEONG_COUNT)
+}
+
+// Index of a modern conjoining jongseong, e.g. U+11A8 (ᆨ) as 1
+fn jongseong(c: char) -> Option<u32> {
+  (c as u32).checked_sub(JONGSEONG_BASE).filter(|&i| 0 < i && i < JONGSEONG_COUNT)
+}
+
+// Index of a Hangul Syllable without jongseong, e.g. 가 as 0
+fn open_syllable(c: char) -> Option<u32> {
+  (c as u32)
+    .checked_sub(SYLLABLE_BASE)
+    .filter(|&i| i < CHOSEONG_COUNT * JUNGSEONG_COUNT * JONGSEONG_COUNT && i % JONGSEONG_COUNT == 0)
+}
+
+// Composes a decomposed (NFD) Hangul Syllable at the end of a string,
+// e.g. U+110E U+1162 U+11A8 as 책.
+// Returns `None` if the string does not end with one.
+pub(crate) fn compose(noun: &str) -> Option<char> {
+  let mut chars = noun.chars().rev();
+  let last = chars.next()?;
+  let before = chars.next()?;
+
+  let (syllable, jongseong) = match (jongseong(last), jungseong(before)) {
+    (Some(t), Some(v)) => (choseong(chars.next()?)? * JUNGSEONG_COUNT + v, t),
+    (Some(t), None) => (open_syllable(before)? / JONGSEONG_COUNT, t),
+    (None, _) => (choseong(before)? * JUNGSEONG_COUNT + jungseong(last)?, 0)
+  };
+
+  char::try_from(SYLLABLE_BASE + syllable * JONGSEONG_COUNT + jongseong).ok()
+}
+
+// Consonants and syllables in the order of Enclosed CJK Letters, from ㄱ to ㅎ
+const ENCLOSED_JAMO: [char; 14] = ['ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'];
+const ENCLOSED_SYLLABLES: [char; 14] = ['가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하'];
+
+// Ranges of Halfwidth Hangul Jamo, and the Hangul Compatibility Jamo each range starts with
+const HALFWIDTH: [(char, char, char); 6] = [
+  ('\u{ffa0}', '\u{ffa0}', '\u{3164}'), // filler
+  ('\u{ffa1}', '\u{ffbe}', '\u{3131}'), // ㄱ to ㅎ
+  ('\u{ffc2}', '\u{ffc7}', '\u{314f}'), // ㅏ to ㅔ
+  ('\u{ffca}', '\u{ffcf}', '\u{3155}'), // ㅕ to ㅚ
+  ('\u{ffd2}', '\u{ffd7}', '\u{315b}'), // ㅛ to ㅟ
+  ('\u{ffda}', '\u{ffdc}', '\u{3161}')  // ㅡ to ㅣ
+];
+
+// Maps a Halfwidth Hangul Jamo to its Hangul Compatibility Jamo, and leaves other characters as is
+fn fullwidth(c: char) -> char {
+  HALFWIDTH
+    .iter()
+    .find(|&&(first, last, _)| first <= c && c <= last)
+    .and_then(|&(first, _, compatibility)| {
+      char::from_u32(compatibility as u32 + (c as u32 - first as u32))
+    })
+    .unwrap_or(c)
+}
+
+// Maps a parenthesized or circled Hangul character to the last jamo or syllable enclosed,
+// e.g. ㈜ (주식회사) as 주 and ㉧ as ㅇ.
+// Returns `None` if given character is not an enclosed Hangul character.
+pub(crate) fn unenclose(c: char) -> Option<char> {
+  let code = c as usize;
+
+  match c {
+    '\u{3200}'..='\u{320d}' => Some(ENCLOSED_JAMO[code - 0x3200]),
+    '\u{320e}'..='\u{321b}' => Some(ENCLOSED_SYLLABLES[code - 0x320e]),
+    '\u{3260}'..='\u{326d}' => Some(ENCLOSED_JAMO[code - 0x3260]),
+    '\u{326e}'..='\u{327b}' => Some(ENCLOSED_SYLLABLES[code - 0x326e]),
+    '㈜' => Some('주'),
+    '㈝' => Some('전'),
+    '㈞' => Some('후'),
+    '㉼' => Some('고'),
+    '㉽' => Some('의'),
+    '㉾' => Some('우'),
+    _ => None
+  }
+}
+
+// Classifies the Korean name of a Hangul Compatibility Jamo, e.g. ㄱ as 기역 and ㅏ as 아.
+// Every consonant name ends with the consonant itself, so it is closed but ㄹ (리을).
+// Halfwidth jamo are read as their compatibility jamo.
+// Returns `None` if given character is not a compatibility jamo.
+pub(crate) fn letter(c: char) -> Option<JongseongKind> {
+  match fullwidth(c) {
+    'ㄹ' => Some(JongseongKind::Rieul),
+    '\u{3131}'..='\u{314e}' | '\u{3165}'..='\u{3186}' => Some(JongseongKind::Closed),
+    '\u{314f}'..='\u{3163}' | '\u{3187}'..='\u{318e}' => Some(JongseongKind::Open),
+    _ => None
+  }
+}
+
+// Classifies how a Hangul Compatibility Jamo is pronounced in internet slang,
+// e.g. ㅋ as 크 and ㅠ as 유, but ㅇ as 응.
+// Returns `None` if given character is not a compatibility jamo.
+pub(crate) fn pronounce(c: char) -> Option<JongseongKind> {
+  match fullwidth(c) {
+    'ㅇ' => Some(JongseongKind::Closed),
+    _ => letter(c).map(|_| JongseongKind::Open)
+  }
+}
+
+// Returns where a trailing run of compatibility jamo starts, such as ㅋㅋ in 재밌다ㅋㅋ.
+// A run is either attached to a preceding character or made of two or more jamo,
+// so that a single jamo such as ㄱ in 첫 글자는 ㄱ is not a run.
+pub(crate) fn run(noun: &str) -> Option<usize> {
+  let start = noun
+    .char_indices()
+    .rev()
+    .take_while(|&(_, c)| letter(c).is_some())
+    .last()
+    .map(|(i, _)| i)?;
+  let attached = matches!(noun[..start].chars().last(), Some(c) if !c.is_whitespace());
+  let long = noun[start..].chars().nth(1).is_some();
+
+  if attached || long {
+    Some(start)
+  } else {
+    None
+  }
+}
+
+// Classifies a lone conjoining jungseong or jongseong at the end of a string as the ending itself,
+// where it cannot be composed with the preceding character.
+// Like Hangul Syllables, compound jongseong pronounced as ㄹ are treated as ㄹ.
+// Returns `None` if given character is neither.
+pub(crate) fn conjoining(c: char) -> Option<JongseongKind> {
+  match c {
+    '\u{11af}' | '\u{11b2}' | '\u{11b3}' | '\u{11b4}' | '\u{11b6}' => Some(JongseongKind::Rieul),
+    _ if jongseong(c).is_some() => Some(JongseongKind::Closed),
+    _ if jungseong(c).is_some() => Some(JongseongKind::Open),
+    _ => None
+  }
+}
@@ -0,0 +1,94 @@
+use crate::JongseongKind;
+
+// Endings of English words and the jongseong of their typical Korean reading.
+// Endings are matched in this order, and a word matching none of them is read as open,
+// e.g. coffee (커피), computer (컴퓨터), bus (버스).
+const ENDINGS: [(&str, JongseongKind); 22] = [
+  ("le", JongseongKind::Rieul),  // Google (구글)
+  ("me", JongseongKind::Closed), // game (게임)
+  ("ne", JongseongKind::Closed), // phone (폰)
+  ("ng", JongseongKind::Closed), // king (킹)
+  ("ck", JongseongKind::Closed), // rock (록)
+  ("ct", JongseongKind::Open),   // project (프로젝트)
+  ("ft", JongseongKind::Open),   // gift (기프트)
+  ("nt", JongseongKind::Open),   // font (폰트)
+  ("pt", JongseongKind::Open),   // script (스크립트)
+  ("st", JongseongKind::Open),   // test (테스트)
+  ("nk", JongseongKind::Open),   // pink (핑크)
+  ("rk", JongseongKind::Open),   // park (파크)
+  ("sk", JongseongKind::Open),   // desk (데스크)
+  ("b", JongseongKind::Closed),  // web (웹)
+  ("c", JongseongKind::Closed),  // magic (매직)
+  ("g", JongseongKind::Closed),  // big (빅)
+  ("k", JongseongKind::Closed),  // book (북)
+  ("m", JongseongKind::Closed),  // team (팀)
+  ("n", JongseongKind::Closed),  // Nolan (놀란)
+  ("p", JongseongKind::Closed),  // shop (숍)
+  ("t", JongseongKind::Closed),  // chat (챗)
+  ("l", JongseongKind::Rieul)    // Excel (엑셀)
+];
+
+// Jongseong of the Korean names of uppercase Latin letters, e.g. L as 엘, M as 엠, P as 피
+fn letter(c: char) -> JongseongKind {
+  match c {
+    'L' | 'R' => JongseongKind::Rieul,
+    'M' | 'N' => JongseongKind::Closed,
+    _ => JongseongKind::Open
+  }
+}
+
+// Trailing run of ASCII letters
+pub(crate) fn word(noun: &str) -> &str {
+  let start = noun
+    .char_indices()
+    .rev()
+    .take_while(|&(_, c)| c.is_ascii_alphabetic())
+    .last()
+    .map_or(noun.len(), |(i, _)| i);
+
+  &noun[start..]
+}
+
+// Guesses the jongseong of the Korean reading of an English word.
+// Returns `None` if the string does not end with an ASCII letter.
+pub(crate) fn heuristic(noun: &str) -> Option<JongseongKind> {
+  let word = word(noun).to_ascii_lowercase();
+
+  if word.is_empty() {
+    return None;
+  }
+
+  Some(
+    ENDINGS
+      .iter()
+      .find(|&&(ending, _)| word.ends_with(ending))
+      .map_or(JongseongKind::Open, |&(_, kind)| kind)
+  )
+}
+
+// Reads a trailing acronym of two or more uppercase letters letter by letter,
+// e.g. SQL as 에스큐엘, API as 에이피아이.
+// Returns `None` if the string does not end with an acronym.
+pub(crate) fn acronym(noun: &str) -> Option<JongseongKind> {
+  let last = noun.chars().last()?;
+  let len = noun.chars().rev().take_while(char::is_ascii_uppercase).count();
+
+  if len < 2 {
+    return None;
+  }
+
+  Some(letter(last))
+}
+
+// Guesses the jongseong of a romanized Korean name, e.g. Yujin (유진), Minseok (민석), Seul (슬).
+// Final consonants of the Revised Romanization are k, t, p, m, n, ng and l,
+// and b and g are accepted for names romanized otherwise, e.g. Jaeseob (재섭).
+// Returns `None` if the string does not end with a vowel or one of those consonants.
+pub(crate) fn romanized(noun: &str) -> Option<JongseongKind> {
+  match word(noun).chars().last()?.to_ascii_lowercase() {
+    'a' | 'e' | 'i' | 'o' | 'u' => Some(JongseongKind::Open),
+    'b' | 'g' | 'k' | 'm' | 'n' | 'p' | 't' => Some(JongseongKind::Closed),
+    'l' => Some(JongseongKind::Rieul),
+    _ => None
+  }
+}
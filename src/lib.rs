@@ -107,6 +107,7 @@
 //! 
 //! If given [`String`] ends with character other than Hangul Syllable,
 //! it pushes `이(가)` formatted josa.
+//! With the `loanwords` feature, words in its dictionary such as curry are read by their transliteration instead.
 //! 
 //! ```
 //! use josa::{JosaExt, IGa, Eu};
@@ -114,15 +115,380 @@
 //! let mut curry = "curry".to_owned();
 //! curry.push_josa(IGa);
 //! 
+//! # #[cfg(not(feature = "loanwords"))]
 //! assert_eq!(curry, "curry이(가)");
 //!
 //!
 //! let mut pioneer = "pioneer".to_owned();
 //! pioneer.push_josa(Eu);
 //! 
+//! # #[cfg(not(feature = "loanwords"))]
 //! assert_eq!(pioneer, "pioneer(으)"); // you can append 로서
 //! ```
+//!
+//! ### Trailing whitespace
+//!
+//! Spaces, tabs and line breaks at the end of given [`String`] are skipped,
+//! so josa is selected by the last character before them.
+//! [`push_josa`] still appends josa after the whole [`String`], leaving the whitespace as is.
+//! A [`String`] of whitespace only is treated like an empty [`String`].
+//! Other spaces such as no-break space (U+00A0) and ideographic space (U+3000) count as whitespace too.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut cat = "고양이\n".to_owned();
+//! cat.push_josa(IGa);
+//!
+//! assert_eq!(cat, "고양이\n가");
+//! ```
+//!
+//! Invisible characters such as zero width spaces, soft hyphens, bidi controls and variation selectors
+//! are skipped the same way.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut cat = "고양이\u{200b}".to_owned();
+//! cat.push_josa(IGa);
+//!
+//! assert_eq!(cat, "고양이\u{200b}가");
+//! ```
+//!
+//! So are ANSI escape sequences that color the output of terminals.
+//! [`push_josa`] appends josa after them, so that josa isn't colored.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut cat = "\x1b[1;31m고양이\x1b[0m".to_owned();
+//! cat.push_josa(IGa);
+//!
+//! assert_eq!(cat, "\x1b[1;31m고양이\x1b[0m가");
+//! ```
+//!
+//! ### Trailing punctuation
+//!
+//! Sentence punctuation at the end of given [`String`], `.`, `,`, `!`, `?`, `…`, `·` and `~`, is skipped as well.
+//! Since josa belongs to the noun, [`push_josa`] inserts josa before the punctuation.
+//! See [`Selector::punctuation`] to append it after the punctuation instead.
+//!
+//! ```
+//! use josa::{JosaExt, IGa, EunNeun};
+//!
+//! let mut cat = "고양이...".to_owned();
+//! let mut jackpot = "대박!".to_owned();
+//!
+//! cat.push_josa(IGa);
+//! jackpot.push_josa(EunNeun);
+//!
+//! assert_eq!(cat, "고양이가...");
+//! assert_eq!(jackpot, "대박은!");
+//! ```
+//!
+//! ### Trailing symbols
+//!
+//! Trademark and reference symbols at the end of given [`String`], `™`, `®`, `©`, `※`, `*`, `†` and `‡`, are skipped too.
+//! Unlike punctuation, [`push_josa`] appends josa after them.
+//! See [`Selector::symbols`] to insert it before the symbols instead.
+//!
+//! ```
+//! use josa::{JosaExt, EulReul};
+//!
+//! let mut windows = "윈도우®".to_owned();
+//! windows.push_josa(EulReul);
+//!
+//! assert_eq!(windows, "윈도우®를");
+//! ```
+//!
+//! ### Closing quotes and brackets
+//!
+//! Closing quotes and brackets at the end of given [`String`] are skipped,
+//! and [`push_josa`] appends josa after them as usual.
+//! Punctuation inside them stays inside, and josa still goes after the closing mark.
+//!
+//! ```
+//! use josa::{JosaExt, EunNeun, IGa};
+//!
+//! let mut book = "《홍길동전》".to_owned();
+//! let mut flower = "'불꽃'".to_owned();
+//! let mut title = "《고양이!》".to_owned();
+//!
+//! book.push_josa(EunNeun);
+//! flower.push_josa(IGa);
+//! title.push_josa(IGa);
+//!
+//! assert_eq!(book, "《홍길동전》은");
+//! assert_eq!(flower, "'불꽃'이");
+//! assert_eq!(title, "《고양이!》가");
+//! ```
+//!
+//! ### Trailing emoji
+//!
+//! Emoji at the end of given [`String`] are skipped, and [`push_josa`] appends josa after them.
+//! See [`Selector::emoji`] to insert it before the emoji instead.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut cat = "고양이🐱".to_owned();
+//! cat.push_josa(IGa);
+//!
+//! assert_eq!(cat, "고양이🐱가");
+//! ```
+//!
+//! ### Parenthetical
+//!
+//! If given [`String`] ends with a balanced parenthetical such as (주) or (가칭),
+//! josa is selected by the character before the opening parenthesis,
+//! and [`push_josa`] appends josa after the parenthetical.
+//! See [`Selector::parenthetical_contents`] to select by the contents instead.
+//!
+//! ```
+//! use josa::{JosaExt, EunNeun};
+//!
+//! let mut company = "한화(주)".to_owned();
+//! let mut city = "서울시(가칭)".to_owned();
+//!
+//! company.push_josa(EunNeun);
+//! city.push_josa(EunNeun);
+//!
+//! assert_eq!(company, "한화(주)는");
+//! assert_eq!(city, "서울시(가칭)는");
+//! ```
+//!
+//! ### Jamo
+//!
+//! If given [`String`] ends with a Hangul Compatibility Jamo, josa is selected by the name of the jamo,
+//! e.g. ㄱ as 기역 and ㅏ as 아.
+//! Halfwidth jamo such as ﾡ are read like their compatibility jamo.
+//! Parenthesized and circled Hangul such as ㈜ (주식회사) and ㉧ are read like the jamo or syllable enclosed.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut giyeok = "첫 글자는 ㄱ".to_owned();
+//! let mut a = "ㅏ".to_owned();
+//!
+//! giyeok.push_josa(IGa);
+//! a.push_josa(IGa);
+//!
+//! assert_eq!(giyeok, "첫 글자는 ㄱ이");
+//! assert_eq!(a, "ㅏ가");
+//! ```
+//!
+//! A trailing run of jamo in internet slang, such as ㅋㅋ and ㅠㅠ, is skipped instead.
+//! See [`Selector::jamo_runs`] to read it as it is pronounced.
+//!
+//! ```
+//! use josa::{JosaExt, EunNeun};
+//!
+//! let mut thanks = "고마워ㅠㅠ".to_owned();
+//! thanks.push_josa(EunNeun);
+//!
+//! assert_eq!(thanks, "고마워ㅠㅠ는");
+//! ```
+//!
+//! ### Decomposed Hangul
+//!
+//! Hangul Syllables decomposed into conjoining jamo (NFD), as in macOS file names,
+//! are composed before josa is selected.
+//! A lone conjoining jongseong or jungseong that cannot be composed is read as the ending itself.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut cat = "\u{1100}\u{1169}\u{110b}\u{1163}\u{110b}\u{1175}".to_owned(); // 고양이
+//! cat.push_josa(IGa);
+//!
+//! assert_eq!(cat, "\u{1100}\u{1169}\u{110b}\u{1163}\u{110b}\u{1175}가");
+//! ```
+//!
+//! ### Digits
+//!
+//! If given [`String`] ends with digits,
+//! josa is selected by the last syllable of the Korean reading of the number,
+//! e.g. 3 as 삼, 10 as 십, and 5000000 as 오백만.
+//! Thousands separators are allowed between digits, so 10,000 is read as 만.
+//! Digits after a decimal point are read one by one, so 4.5 ends with 오.
+//! Numbers with more than 20 digits are treated like non Hangul Syllable characters.
+//! Decimal digits of other scripts, such as ٣ and ३, are read like ASCII digits.
+//! Enclosed numbers such as ② and ⑽ are read as the numbers they enclose,
+//! and keycap emoji such as 1️⃣ as their digits.
+//! Superscript and subscript digits are read as a number of their own, so 10³ ends with 삼.
+//!
+//! ```
+//! use josa::{JosaExt, IGa, EuRo};
+//!
+//! let mut level = "레벨 3".to_owned();
+//! let mut seven = "7".to_owned();
+//! let mut ten = "10".to_owned();
+//!
+//! level.push_josa(IGa);
+//! seven.push_josa(EuRo);
+//! ten.push_josa(EuRo);
+//!
+//! assert_eq!(level, "레벨 3이");
+//! assert_eq!(seven, "7로");
+//! assert_eq!(ten, "10으로");
+//! ```
+//!
+//! ### Units
+//!
+//! If given [`String`] ends with a unit of measurement after a number, such as 3kg or 50%,
+//! josa is selected by the Korean reading of the unit, e.g. kg as 킬로그램 and % as 퍼센트.
+//! See [`Selector::unit`] to add your own units.
+//!
+//! ```
+//! use josa::{JosaExt, EunNeun, IGa};
+//!
+//! let mut weight = "3kg".to_owned();
+//! let mut ratio = "50%".to_owned();
+//!
+//! weight.push_josa(EunNeun);
+//! ratio.push_josa(IGa);
+//!
+//! assert_eq!(weight, "3kg은");
+//! assert_eq!(ratio, "50%가");
+//! ```
+//!
+//! ### Prices
+//!
+//! If given [`String`] is a price in a known currency, such as $5 or 5 USD,
+//! josa is selected by the Korean name of the currency read after the amount, e.g. $5 as 오 달러.
+//! Prices in unknown currencies are read like plain numbers.
+//!
+//! ```
+//! use josa::{JosaExt, EunNeun, IGa};
+//!
+//! let mut dollars = "$5".to_owned();
+//! let mut yen = "¥100".to_owned();
+//!
+//! dollars.push_josa(IGa);
+//! yen.push_josa(EunNeun);
+//!
+//! assert_eq!(dollars, "$5가");
+//! assert_eq!(yen, "¥100은");
+//! ```
+//!
+//! ### Roman numerals
+//!
+//! If given [`String`] ends with a Roman numeral, such as Ⅶ or a separate word of I, V and X like II,
+//! josa is selected by the Korean reading of its value just like digits, e.g. Ⅶ as 칠.
+//! A single letter such as I is not read as a numeral, since it may be an English word.
+//! See [`Selector::single_roman_numerals`] to read it as a numeral.
+//!
+//! ```
+//! use josa::{JosaExt, EuRo, EunNeun};
+//!
+//! let mut seven = "파이널판타지 Ⅶ".to_owned();
+//! let mut three = "시즌 III".to_owned();
+//!
+//! seven.push_josa(EuRo);
+//! three.push_josa(EunNeun);
+//!
+//! assert_eq!(seven, "파이널판타지 Ⅶ로");
+//! assert_eq!(three, "시즌 III은");
+//! ```
+//!
+//! ### Acronyms
+//!
+//! If given [`String`] ends with two or more uppercase Latin letters,
+//! it is read letter by letter, e.g. SQL as 에스큐엘.
+//!
+//! ```
+//! use josa::{JosaExt, EulReul, EuRo};
+//!
+//! let mut sql = "SQL".to_owned();
+//! let mut api = "API".to_owned();
+//!
+//! sql.push_josa(EulReul);
+//! api.push_josa(EuRo);
+//!
+//! assert_eq!(sql, "SQL을");
+//! assert_eq!(api, "API로");
+//! ```
+//!
+//! ### Fullwidth forms
+//!
+//! Fullwidth forms such as ３ and ＶＩＰ are read like their ASCII characters.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut level = "레벨３".to_owned();
+//! let mut vip = "ＶＩＰ".to_owned();
+//!
+//! level.push_josa(IGa);
+//! vip.push_josa(IGa);
+//!
+//! assert_eq!(level, "레벨３이");
+//! assert_eq!(vip, "ＶＩＰ가");
+//! ```
+//!
+//! Mathematical alphanumeric symbols such as 𝐀 and 𝟑, often used as styled text,
+//! are read like their ASCII letters and digits as well.
+//!
+//! ```
+//! use josa::{JosaExt, IGa};
+//!
+//! let mut level = "𝑳𝒆𝒗𝒆𝒍 𝟑".to_owned();
+//! level.push_josa(IGa);
+//!
+//! assert_eq!(level, "𝑳𝒆𝒗𝒆𝒍 𝟑이");
+//! ```
+//!
+//! ### Compound jongseong
+//!
+//! Compound jongseong pronounced as ㄹ (ㄼ, ㄽ, ㄾ, ㅀ) are treated like ㄹ,
+//! so 으로/로 and its friends select 로 after them.
+//!
+//! ```
+//! use josa::{JosaExt, EuRo};
+//!
+//! let mut eight = "여덟".to_owned();
+//! let mut chicken = "닭".to_owned();
+//!
+//! eight.push_josa(EuRo);
+//! chicken.push_josa(EuRo);
+//!
+//! assert_eq!(eight, "여덟로");
+//! assert_eq!(chicken, "닭으로");
+//! ```
 //! 
+//! # Selector
+//!
+//! [`Selector`] provides opt-in options on top of the default behavior,
+//! such as rewriting pronouns 나, 저, 너 into 내가, 제가, 네가.
+//!
+//! ```
+//! use josa::{Selector, IGa};
+//!
+//! let selector = Selector::new().pronoun_contractions(true);
+//!
+//! let mut you = "너".to_owned();
+//! selector.push_josa(&mut you, IGa);
+//!
+//! assert_eq!(you, "네가");
+//! ```
+//!
+//! # Features
+//!
+//! - `loanwords`: a dictionary of about 4,000 English loanwords and their Korean transliterations,
+//!   consulted for strings ending in Latin letters,
+//!   e.g. orange as 오렌지 and internet as 인터넷.
+//!   Unknown words fall back to [`Selector::latin_heuristic`] if enabled.
+//!   See [`is_known_loanword`] to check whether a word is in the dictionary.
+//! - `hanja`: Korean readings of Hanja in KS X 1001,
+//!   so that a string ending in Hanja is selected by its reading,
+//!   e.g. 株式會社 as 주식회사.
+//!   Hanja without a known reading are treated like other non Hangul Syllable characters.
+//! - `yethangul`: archaic Hangul syllables (옛한글) written in conjoining jamo,
+//!   including the ones in Hangul Jamo Extended-A and B,
+//!   classified by whether they end with a jongseong, e.g. ᄒᆞᆫ as closed.
+//! - `grapheme`: [`Selector::grapheme_aware`], which classifies a string
+//!   by the base character of its last extended grapheme cluster, e.g. 각́ as 각.
+//!
 //! # Supported josas
 //!
 //! Currently we support:
@@ -131,8 +497,64 @@
 //! - 이/가
 //! - 을/를
 //! - 과/와
-//! - 이/(empty) (이다/다, 이나/나, 이란/란, 이든가/든가, 이나마/나마, 이야말로/야말로, 이랑/랑, 이여/여, 이며/며)
-//! - 으/(empty) (으로/로, 으로서/로서, 으로써/로써, 으로부터/로부터)
+//! - 아/야
+//! - 이/(empty), the `이` part of the josas below such as 이다/다
+//! - 으/(empty), the `으` part of the josas below such as 으로/로
+//! - 으로/로
+//! - 으로서/로서
+//! - 으로써/로써
+//! - 으로부터/로부터
+//! - 이다/다
+//! - 이나/나
+//! - 이란/란
+//! - 이든가/든가
+//! - 이나마/나마
+//! - 이야말로/야말로
+//! - 이랑/랑
+//! - 이여/여
+//! - 이며/며
+//! - 이에요/예요
+//! - 이었다/였다
+//! - 이라고/라고
+//! - 이라는/라는
+//! - 이라도/라도
+//! - 이라면/라면
+//! - 이든지/든지
+//! - 이니까/니까
+//! - 이지만/지만
+//! - 이라서/라서
+//! - 이시여/시여
+//! - 은커녕/는커녕
+//! - 으로의/로의
+//! - 과의/와의
+//! - 과는/와는
+//! - 과도/와도
+//! - 이고/고
+//! - 이거나/거나
+//! - 이되/되
+//! - 이냐/냐
+//! - 이면/면
+//! - 이니/니
+//! - 이어서/여서
+//! - 이던가/던가
+//! - 이네/네
+//! - 이라든지/라든지
+//! - 이란다/란다
+//! - 이라니/라니
+//! - 이라면서/라면서
+//! - 이라곤/라곤
+//! - 이로다/로다
+//! - 이올시다/올시다
+//! - 이란들/란들
+//! - 이라거나/라거나
+//! - 이라기보다/라기보다
+//! - 이야/야
+//! - 이라야/라야
+//! - 이기에/기에
+//!
+//! - 도, 만, 까지, 부터, 마다, 에, 에서, 에게, 한테, 처럼, 보다, 밖에, 께서, 께, 이자 (invariant)
+//!
+//! Conjugations of the copula 이다 such as 입니다 and 이었습니다/였습니다 are in [`copula`] module.
 //! 
 //! [josa]: https://en.wikipedia.org/wiki/Korean_postpositions
 //! [`push_josa`]: trait.JosaExt.html#tymethod.push_josa
@@ -140,22 +562,63 @@
 //! [`select`]: fn.select.html
 //! [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
 //! [`Josa`]: enum.Josa.html
+//! [`copula`]: copula/index.html
+//! [`Selector`]: struct.Selector.html
+//! [`Selector::latin_heuristic`]: struct.Selector.html#method.latin_heuristic
+//! [`Selector::punctuation`]: struct.Selector.html#method.punctuation
+//! [`Selector::symbols`]: struct.Selector.html#method.symbols
+//! [`Selector::unit`]: struct.Selector.html#method.unit
+//! [`Selector::single_roman_numerals`]: struct.Selector.html#method.single_roman_numerals
+//! [`Selector::parenthetical_contents`]: struct.Selector.html#method.parenthetical_contents
+//! [`Selector::emoji`]: struct.Selector.html#method.emoji
+//! [`Selector::jamo_runs`]: struct.Selector.html#method.jamo_runs
+//! [`Selector::grapheme_aware`]: struct.Selector.html#method.grapheme_aware
+//! [`is_known_loanword`]: fn.is_known_loanword.html
 
 use std::convert::TryFrom;
 use std::ops::{Add, AddAssign};
 
 use hangul::HangulExt;
 
+mod currency;
 mod error;
 pub use error::{Error, Result};
 
+mod greek;
+mod html;
+mod jamo;
+mod latin;
+mod markdown;
+mod math;
+mod number;
+mod roman;
+mod selector;
+mod trailing;
+mod unit;
+mod width;
+pub use selector::{JamoRun, Placement, Selector};
+
+#[cfg(feature = "grapheme")]
+mod grapheme;
+#[cfg(feature = "hanja")]
+mod hanja;
+#[cfg(feature = "loanwords")]
+mod loanwords;
+#[cfg(feature = "yethangul")]
+mod yethangul;
+#[cfg(feature = "loanwords")]
+pub use loanwords::is_known_loanword;
+
+pub mod copula;
+
 pub use Josa::*;
 
 // First group
 const EUN: &str = "은";
 const NEUN: &str = "는";
 
-const I: &str = "이";
+// Not named `I`, which would shadow `Josa::I` and keep it out of the `pub use Josa::*` re-export above
+const I_: &str = "이";
 const GA: &str = "가";
 
 const EUL: &str = "을";
@@ -164,10 +627,187 @@ const REUL: &str = "를";
 const GWA: &str = "과";
 const WA: &str = "와";
 
+const A: &str = "아";
+const YA: &str = "야";
+
 // Second group
 const EU: &str = "으";
 
+const EU_RO: &str = "으로";
+const RO: &str = "로";
+
+const EU_RO_SEO: &str = "으로서";
+const RO_SEO: &str = "로서";
+
+const EU_RO_SSEO: &str = "으로써";
+const RO_SSEO: &str = "로써";
+
+const EU_RO_BUTEO: &str = "으로부터";
+const RO_BUTEO: &str = "로부터";
+
+const IDA: &str = "이다";
+const DA: &str = "다";
+
+const INA: &str = "이나";
+const NA: &str = "나";
+
+const IRAN: &str = "이란";
+const RAN: &str = "란";
+
+const IDEUNGA: &str = "이든가";
+const DEUNGA: &str = "든가";
 
+const INAMA: &str = "이나마";
+const NAMA: &str = "나마";
+
+const IYAMALLO: &str = "이야말로";
+const YAMALLO: &str = "야말로";
+
+const IRANG: &str = "이랑";
+const RANG: &str = "랑";
+
+const IYEO: &str = "이여";
+const YEO: &str = "여";
+
+const IMYEO: &str = "이며";
+const MYEO: &str = "며";
+
+const IEYO: &str = "이에요";
+const YEYO: &str = "예요";
+
+const IEOTDA: &str = "이었다";
+const YEOTDA: &str = "였다";
+
+const IRAGO: &str = "이라고";
+const RAGO: &str = "라고";
+
+const IRANEUN: &str = "이라는";
+const RANEUN: &str = "라는";
+
+const IRADO: &str = "이라도";
+const RADO: &str = "라도";
+
+const IRAMYEON: &str = "이라면";
+const RAMYEON: &str = "라면";
+
+const IDEUNJI: &str = "이든지";
+const DEUNJI: &str = "든지";
+
+const INIKKA: &str = "이니까";
+const NIKKA: &str = "니까";
+
+const IJIMAN: &str = "이지만";
+const JIMAN: &str = "지만";
+
+const IRASEO: &str = "이라서";
+const RASEO: &str = "라서";
+
+const IJA: &str = "이자";
+
+const ISIYEO: &str = "이시여";
+const SIYEO: &str = "시여";
+
+const EUN_KEONYEONG: &str = "은커녕";
+const NEUN_KEONYEONG: &str = "는커녕";
+
+const EU_RO_UI: &str = "으로의";
+const RO_UI: &str = "로의";
+
+const GWA_UI: &str = "과의";
+const WA_UI: &str = "와의";
+
+const GWA_NEUN: &str = "과는";
+const WA_NEUN: &str = "와는";
+
+const GWA_DO: &str = "과도";
+const WA_DO: &str = "와도";
+
+const IGO: &str = "이고";
+const GO: &str = "고";
+
+const IGEONA: &str = "이거나";
+const GEONA: &str = "거나";
+
+const IDOE: &str = "이되";
+const DOE: &str = "되";
+
+const INYA: &str = "이냐";
+const NYA: &str = "냐";
+
+const IMYEON: &str = "이면";
+const MYEON: &str = "면";
+
+const INI: &str = "이니";
+const NI: &str = "니";
+
+const IEOSEO: &str = "이어서";
+const YEOSEO: &str = "여서";
+
+const IDEONGA: &str = "이던가";
+const DEONGA: &str = "던가";
+
+const INE: &str = "이네";
+const NE: &str = "네";
+
+const IRADEUNJI: &str = "이라든지";
+const RADEUNJI: &str = "라든지";
+
+const IRANDA: &str = "이란다";
+const RANDA: &str = "란다";
+
+const IRANI: &str = "이라니";
+const RANI: &str = "라니";
+
+const IRAMYEONSEO: &str = "이라면서";
+const RAMYEONSEO: &str = "라면서";
+
+const IRAGON: &str = "이라곤";
+const RAGON: &str = "라곤";
+
+const IRODA: &str = "이로다";
+const RODA: &str = "로다";
+
+const IOLSIDA: &str = "이올시다";
+const OLSIDA: &str = "올시다";
+
+const IRANDEUL: &str = "이란들";
+const RANDEUL: &str = "란들";
+
+const IRAGEONA: &str = "이라거나";
+const RAGEONA: &str = "라거나";
+
+const IRAGIBODA: &str = "이라기보다";
+const RAGIBODA: &str = "라기보다";
+
+const IYA: &str = "이야";
+
+const IRAYA: &str = "이라야";
+const RAYA: &str = "라야";
+
+const IGIE: &str = "이기에";
+const GIE: &str = "기에";
+
+// Invariant group
+const DO: &str = "도";
+const MAN: &str = "만";
+const KKAJI: &str = "까지";
+const BUTEO: &str = "부터";
+const MADA: &str = "마다";
+const E_: &str = "에";
+const ESEO: &str = "에서";
+const EGE: &str = "에게";
+const HANTE: &str = "한테";
+const CHEOREOM: &str = "처럼";
+const BODA: &str = "보다";
+const BAKKE: &str = "밖에";
+const KKESEO: &str = "께서";
+const KKE: &str = "께";
+
+
+// Compound jongseong ㄼ, ㄽ, ㄾ and ㅀ are classified as Rieul,
+// because their representative sound is ㄹ (e.g. 여덟 [여덜], 외곬 [외골]).
+// Other compound jongseong such as ㄺ (닭 [닥]) and ㅄ (값 [갑]) are Closed.
+#[derive(Clone, Copy)]
 enum JongseongKind {
   Open,
   Rieul,
@@ -180,7 +820,7 @@ impl TryFrom<char> for JongseongKind {
   fn try_from(value: char) -> Result<JongseongKind> {
     match value.jongseong()? {
       Some(jongseong) => match jongseong {
-        'ㄹ' => Ok(JongseongKind::Rieul),
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => Ok(JongseongKind::Rieul),
         _ => Ok(JongseongKind::Closed)
       },
       None => Ok(JongseongKind::Open)
@@ -190,6 +830,9 @@ impl TryFrom<char> for JongseongKind {
 
 
 /// Enum of [josas](https://en.wikipedia.org/wiki/Korean_grammar#Postpositions) that are selected depending on the string in front of it.
+///
+/// Invariant josas such as [`Do`](#variant.Do) are also included, so that every josa goes through the same API.
+/// They are selected regardless of the last character, unless the string is empty.
 #[derive(Clone, Copy)]
 pub enum Josa {
   /// 은/는 
@@ -200,51 +843,541 @@ pub enum Josa {
   EulReul,
   /// 과/와
   GwaWa,
+  /// 아/야
+  AYa,
   /// 이다/다, 이나/나, 이란/란, 이든가/든가, 이나마/나마, 이야말로/야말로, 이랑/랑, 이여/여, 이며/며
   I,
   /// 으로/로, 으로서/로서, 으로써/로써, 으로부터/로부터
-  Eu
+  ///
+  /// It selects `으` part only. Prefer [`EuRo`](#variant.EuRo) for 으로/로.
+  Eu,
+  /// 으로/로
+  EuRo,
+  /// 으로서/로서
+  EuRoSeo,
+  /// 으로써/로써
+  ///
+  /// ```
+  /// use josa::select;
+  /// use josa::EuRoSsi;
+  /// # use josa::Error;
+  ///
+  /// assert_eq!(select("손", EuRoSsi)?, "으로써");
+  /// assert_eq!(select("칼", EuRoSsi)?, "로써");
+  /// assert_eq!(select("바다", EuRoSsi)?, "로써");
+  /// # Ok::<(), Error>(())
+  /// ```
+  EuRoSsi,
+  /// 으로부터/로부터
+  EuRoButeo,
+  /// 이다/다
+  Ida,
+  /// 이나/나
+  Ina,
+  /// 이란/란
+  Iran,
+  /// 이든가/든가
+  Ideunga,
+  /// 이나마/나마
+  Inama,
+  /// 이야말로/야말로
+  Iyamallo,
+  /// 이랑/랑
+  Irang,
+  /// 이여/여
+  Iyeo,
+  /// 이며/며
+  Imyeo,
+  /// 이에요/예요
+  Ieyo,
+  /// 이었다/였다
+  Ieotda,
+  /// 이라고/라고
+  Irago,
+  /// 이라는/라는
+  Iraneun,
+  /// 이라도/라도
+  Irado,
+  /// 이라면/라면
+  ///
+  /// ```
+  /// use josa::Iramyeon;
+  ///
+  /// let you = "너".to_owned();
+  /// let boss = "사장님".to_owned();
+  ///
+  /// assert_eq!(
+  ///   format!("{} 어떻게 할래? {} 이렇게 하겠지.", you + Iramyeon, boss + Iramyeon),
+  ///   "너라면 어떻게 할래? 사장님이라면 이렇게 하겠지."
+  /// );
+  /// ```
+  Iramyeon,
+  /// 이든지/든지
+  Ideunji,
+  /// 이니까/니까
+  Inikka,
+  /// 이지만/지만
+  Ijiman,
+  /// 이라서/라서
+  Iraseo,
+  /// 이자, which keeps 이 after an open syllable as well, e.g. 작가이자
+  Ija,
+  /// 이시여/시여
+  Isiyeo,
+  /// 은커녕/는커녕
+  EunKeonyeong,
+  /// 으로의/로의
+  EuRoUi,
+  /// 과의/와의
+  GwaUi,
+  /// 과는/와는
+  GwaNeun,
+  /// 과도/와도
+  GwaDo,
+  /// 이고/고
+  ///
+  /// ```
+  /// use josa::{Igo, Ida};
+  ///
+  /// let doctor = "의사".to_owned();
+  /// let writer = "작가".to_owned();
+  /// let seoul = "서울".to_owned();
+  /// let home = "고향".to_owned();
+  ///
+  /// assert_eq!(
+  ///   format!("{} {}", doctor + Igo, writer + Ida),
+  ///   "의사고 작가다"
+  /// );
+  /// assert_eq!(
+  ///   format!("{} {}", seoul + Igo, home + Ida),
+  ///   "서울이고 고향이다"
+  /// );
+  /// ```
+  Igo,
+  /// 이거나/거나
+  Igeona,
+  /// 이되/되
+  Idoe,
+  /// 이냐/냐
+  Inya,
+  /// 이면/면
+  Imyeon,
+  /// 이니/니
+  Ini,
+  /// 이어서/여서
+  Ieoseo,
+  /// 이던가/던가
+  Ideonga,
+  /// 이네/네
+  Ine,
+  /// 이라든지/라든지
+  ///
+  /// ```
+  /// use josa::{JosaExt, Iradeunji};
+  ///
+  /// let mut items = vec!["과일".to_owned(), "채소".to_owned()];
+  ///
+  /// for item in items.iter_mut() {
+  ///   item.push_josa(Iradeunji);
+  /// }
+  ///
+  /// assert_eq!(items.join(" "), "과일이라든지 채소라든지");
+  /// ```
+  Iradeunji,
+  /// 이란다/란다
+  Iranda,
+  /// 이라니/라니
+  Irani,
+  /// 이라면서/라면서
+  Iramyeonseo,
+  /// 이라곤/라곤
+  Iragon,
+  /// 이로다/로다
+  Iroda,
+  /// 이올시다/올시다
+  Iolsida,
+  /// 이란들/란들
+  Irandeul,
+  /// 이라거나/라거나
+  Irageona,
+  /// 이라기보다/라기보다
+  Iragiboda,
+  /// 이야/야
+  Iya,
+  /// 이라야/라야
+  Iraya,
+  /// 이기에/기에
+  Igie,
+  /// 도
+  Do,
+  /// 만
+  Man,
+  /// 까지
+  Kkaji,
+  /// 부터
+  Buteo,
+  /// 마다
+  Mada,
+  /// 에
+  E,
+  /// 에서
+  Eseo,
+  /// 에게
+  Ege,
+  /// 한테
+  Hante,
+  /// 처럼
+  Cheoreom,
+  /// 보다
+  Boda,
+  /// 밖에
+  Bakke,
+  /// 께서 (honorific 이/가)
+  Kkeseo,
+  /// 께 (honorific 에게/한테)
+  Kke
+}
+
+/// Speech register that some josas depend on, such as 에게 and 한테.
+#[derive(Clone, Copy)]
+pub enum Register {
+  /// Formal or written register, e.g. 에게
+  Formal,
+  /// Casual or spoken register, e.g. 한테
+  Casual
 }
 
 impl Josa {
-  fn select(self, c: char) -> Result<&'static str> {
-    match JongseongKind::try_from(c)? {
-      JongseongKind::Open => Ok(self.open()),
-      JongseongKind::Rieul => Ok(self.rieul()),
-      JongseongKind::Closed => Ok(self.closed())
+  /// Subject josa, which is 께서 for an honorific subject and 이/가 otherwise.
+  ///
+  /// # Example
+  /// ```
+  /// use josa::{Josa, JosaExt};
+  ///
+  /// let mut grandfather = "할아버지".to_owned();
+  /// let mut cat = "고양이".to_owned();
+  ///
+  /// grandfather.push_josa(Josa::subject(true));
+  /// cat.push_josa(Josa::subject(false));
+  ///
+  /// assert_eq!(grandfather, "할아버지께서");
+  /// assert_eq!(cat, "고양이가");
+  /// ```
+  pub fn subject(honorific: bool) -> Josa {
+    if honorific {
+      Josa::Kkeseo
+    } else {
+      Josa::IGa
+    }
+  }
+
+  /// Dative josa, which is 께 for an honorific referent,
+  /// and 에게 or 한테 depending on the [`Register`](enum.Register.html) otherwise.
+  ///
+  /// # Example
+  /// ```
+  /// use josa::{Josa, Register};
+  ///
+  /// let parents = "부모님".to_owned();
+  /// let friend = "친구".to_owned();
+  ///
+  /// assert_eq!(parents + Josa::dative(true, Register::Casual), "부모님께");
+  /// assert_eq!(friend + Josa::dative(false, Register::Casual), "친구한테");
+  /// ```
+  pub fn dative(honorific: bool, register: Register) -> Josa {
+    match (honorific, register) {
+      (true, _) => Josa::Kke,
+      (false, Register::Formal) => Josa::Ege,
+      (false, Register::Casual) => Josa::Hante
+    }
+  }
+
+  /// Compose this josa with an invariant tail, such as 으로 + 는 → 으로는.
+  ///
+  /// The head is selected depending on the string in front of it, and the tail is appended as is.
+  /// For a string not ending with a Hangul Syllable, only the head is ambiguous, e.g. `(으)로는`.
+  ///
+  /// # Example
+  /// ```
+  /// use josa::{EuRo, GwaWa};
+  ///
+  /// let mut hand = "손".to_owned();
+  /// let mut friend = "친구".to_owned();
+  /// let mut pioneer = "pioneer".to_owned();
+  ///
+  /// EuRo.then("는").push_to(&mut hand);
+  /// friend += GwaWa.then("도");
+  /// pioneer += EuRo.then("는");
+  ///
+  /// assert_eq!(hand, "손으로는");
+  /// assert_eq!(friend, "친구와도");
+  /// # #[cfg(not(feature = "loanwords"))]
+  /// assert_eq!(pioneer, "pioneer(으)로는");
+  /// ```
+  ///
+  /// The tail may be borrowed from a string built at runtime as well.
+  ///
+  /// ```
+  /// use josa::IGa;
+  /// # use josa::Error;
+  ///
+  /// let tail = String::from("요");
+  ///
+  /// assert_eq!(IGa.then(&tail).select("고양이")?, "가요");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn then(self, tail: &str) -> ComposedJosa<'_> {
+    ComposedJosa { head: self, tail }
+  }
+
+  fn select(self, kind: JongseongKind) -> &'static str {
+    match kind {
+      JongseongKind::Open => self.open(),
+      JongseongKind::Rieul => self.rieul(),
+      JongseongKind::Closed => self.closed()
     }
   }
 
+  fn is_invariant(self) -> bool {
+    matches!(
+      self,
+      Josa::Do | Josa::Man | Josa::Kkaji | Josa::Buteo | Josa::Mada | Josa::E | Josa::Eseo
+      | Josa::Ege | Josa::Hante | Josa::Cheoreom | Josa::Boda | Josa::Bakke | Josa::Kkeseo
+      | Josa::Kke | Josa::Ija
+    )
+  }
+
   fn open(self) -> &'static str {
     match self {
       Josa::EunNeun => NEUN,
       Josa::IGa => GA,
       Josa::EulReul => REUL,
       Josa::GwaWa => WA,
+      Josa::AYa => YA,
       Josa::I => "",
-      Josa::Eu => ""
+      Josa::Eu => "",
+      Josa::EuRo => RO,
+      Josa::EuRoSeo => RO_SEO,
+      Josa::EuRoSsi => RO_SSEO,
+      Josa::EuRoButeo => RO_BUTEO,
+      Josa::Ida => DA,
+      Josa::Ina => NA,
+      Josa::Iran => RAN,
+      Josa::Ideunga => DEUNGA,
+      Josa::Inama => NAMA,
+      Josa::Iyamallo => YAMALLO,
+      Josa::Irang => RANG,
+      Josa::Iyeo => YEO,
+      Josa::Imyeo => MYEO,
+      Josa::Ieyo => YEYO,
+      Josa::Ieotda => YEOTDA,
+      Josa::Irago => RAGO,
+      Josa::Iraneun => RANEUN,
+      Josa::Irado => RADO,
+      Josa::Iramyeon => RAMYEON,
+      Josa::Ideunji => DEUNJI,
+      Josa::Inikka => NIKKA,
+      Josa::Ijiman => JIMAN,
+      Josa::Iraseo => RASEO,
+      Josa::Ija => IJA,
+      Josa::Isiyeo => SIYEO,
+      Josa::EunKeonyeong => NEUN_KEONYEONG,
+      Josa::EuRoUi => RO_UI,
+      Josa::GwaUi => WA_UI,
+      Josa::GwaNeun => WA_NEUN,
+      Josa::GwaDo => WA_DO,
+      Josa::Igo => GO,
+      Josa::Igeona => GEONA,
+      Josa::Idoe => DOE,
+      Josa::Inya => NYA,
+      Josa::Imyeon => MYEON,
+      Josa::Ini => NI,
+      Josa::Ieoseo => YEOSEO,
+      Josa::Ideonga => DEONGA,
+      Josa::Ine => NE,
+      Josa::Iradeunji => RADEUNJI,
+      Josa::Iranda => RANDA,
+      Josa::Irani => RANI,
+      Josa::Iramyeonseo => RAMYEONSEO,
+      Josa::Iragon => RAGON,
+      Josa::Iroda => RODA,
+      Josa::Iolsida => OLSIDA,
+      Josa::Irandeul => RANDEUL,
+      Josa::Irageona => RAGEONA,
+      Josa::Iragiboda => RAGIBODA,
+      Josa::Iya => YA,
+      Josa::Iraya => RAYA,
+      Josa::Igie => GIE,
+      Josa::Do => DO,
+      Josa::Man => MAN,
+      Josa::Kkaji => KKAJI,
+      Josa::Buteo => BUTEO,
+      Josa::Mada => MADA,
+      Josa::E => E_,
+      Josa::Eseo => ESEO,
+      Josa::Ege => EGE,
+      Josa::Hante => HANTE,
+      Josa::Cheoreom => CHEOREOM,
+      Josa::Boda => BODA,
+      Josa::Bakke => BAKKE,
+      Josa::Kkeseo => KKESEO,
+      Josa::Kke => KKE
     }
   }
 
   fn rieul(self) -> &'static str {
     match self {
       Josa::EunNeun => EUN,
-      Josa::IGa => I,
+      Josa::IGa => I_,
       Josa::EulReul => EUL,
       Josa::GwaWa => GWA,
-      Josa::I => I,
-      Josa::Eu => ""
+      Josa::AYa => A,
+      Josa::I => I_,
+      Josa::Eu => "",
+      Josa::EuRo => RO,
+      Josa::EuRoSeo => RO_SEO,
+      Josa::EuRoSsi => RO_SSEO,
+      Josa::EuRoButeo => RO_BUTEO,
+      Josa::Ida => IDA,
+      Josa::Ina => INA,
+      Josa::Iran => IRAN,
+      Josa::Ideunga => IDEUNGA,
+      Josa::Inama => INAMA,
+      Josa::Iyamallo => IYAMALLO,
+      Josa::Irang => IRANG,
+      Josa::Iyeo => IYEO,
+      Josa::Imyeo => IMYEO,
+      Josa::Ieyo => IEYO,
+      Josa::Ieotda => IEOTDA,
+      Josa::Irago => IRAGO,
+      Josa::Iraneun => IRANEUN,
+      Josa::Irado => IRADO,
+      Josa::Iramyeon => IRAMYEON,
+      Josa::Ideunji => IDEUNJI,
+      Josa::Inikka => INIKKA,
+      Josa::Ijiman => IJIMAN,
+      Josa::Iraseo => IRASEO,
+      Josa::Ija => IJA,
+      Josa::Isiyeo => ISIYEO,
+      Josa::EunKeonyeong => EUN_KEONYEONG,
+      Josa::EuRoUi => RO_UI,
+      Josa::GwaUi => GWA_UI,
+      Josa::GwaNeun => GWA_NEUN,
+      Josa::GwaDo => GWA_DO,
+      Josa::Igo => IGO,
+      Josa::Igeona => IGEONA,
+      Josa::Idoe => IDOE,
+      Josa::Inya => INYA,
+      Josa::Imyeon => IMYEON,
+      Josa::Ini => INI,
+      Josa::Ieoseo => IEOSEO,
+      Josa::Ideonga => IDEONGA,
+      Josa::Ine => INE,
+      Josa::Iradeunji => IRADEUNJI,
+      Josa::Iranda => IRANDA,
+      Josa::Irani => IRANI,
+      Josa::Iramyeonseo => IRAMYEONSEO,
+      Josa::Iragon => IRAGON,
+      Josa::Iroda => IRODA,
+      Josa::Iolsida => IOLSIDA,
+      Josa::Irandeul => IRANDEUL,
+      Josa::Irageona => IRAGEONA,
+      Josa::Iragiboda => IRAGIBODA,
+      Josa::Iya => IYA,
+      Josa::Iraya => IRAYA,
+      Josa::Igie => IGIE,
+      Josa::Do => DO,
+      Josa::Man => MAN,
+      Josa::Kkaji => KKAJI,
+      Josa::Buteo => BUTEO,
+      Josa::Mada => MADA,
+      Josa::E => E_,
+      Josa::Eseo => ESEO,
+      Josa::Ege => EGE,
+      Josa::Hante => HANTE,
+      Josa::Cheoreom => CHEOREOM,
+      Josa::Boda => BODA,
+      Josa::Bakke => BAKKE,
+      Josa::Kkeseo => KKESEO,
+      Josa::Kke => KKE
     }
   }
 
   fn closed(self) -> &'static str {
     match self {
       Josa::EunNeun => EUN,
-      Josa::IGa => I,
+      Josa::IGa => I_,
       Josa::EulReul => EUL,
       Josa::GwaWa => GWA,
-      Josa::I => I,
-      Josa::Eu => EU
+      Josa::AYa => A,
+      Josa::I => I_,
+      Josa::Eu => EU,
+      Josa::EuRo => EU_RO,
+      Josa::EuRoSeo => EU_RO_SEO,
+      Josa::EuRoSsi => EU_RO_SSEO,
+      Josa::EuRoButeo => EU_RO_BUTEO,
+      Josa::Ida => IDA,
+      Josa::Ina => INA,
+      Josa::Iran => IRAN,
+      Josa::Ideunga => IDEUNGA,
+      Josa::Inama => INAMA,
+      Josa::Iyamallo => IYAMALLO,
+      Josa::Irang => IRANG,
+      Josa::Iyeo => IYEO,
+      Josa::Imyeo => IMYEO,
+      Josa::Ieyo => IEYO,
+      Josa::Ieotda => IEOTDA,
+      Josa::Irago => IRAGO,
+      Josa::Iraneun => IRANEUN,
+      Josa::Irado => IRADO,
+      Josa::Iramyeon => IRAMYEON,
+      Josa::Ideunji => IDEUNJI,
+      Josa::Inikka => INIKKA,
+      Josa::Ijiman => IJIMAN,
+      Josa::Iraseo => IRASEO,
+      Josa::Ija => IJA,
+      Josa::Isiyeo => ISIYEO,
+      Josa::EunKeonyeong => EUN_KEONYEONG,
+      Josa::EuRoUi => EU_RO_UI,
+      Josa::GwaUi => GWA_UI,
+      Josa::GwaNeun => GWA_NEUN,
+      Josa::GwaDo => GWA_DO,
+      Josa::Igo => IGO,
+      Josa::Igeona => IGEONA,
+      Josa::Idoe => IDOE,
+      Josa::Inya => INYA,
+      Josa::Imyeon => IMYEON,
+      Josa::Ini => INI,
+      Josa::Ieoseo => IEOSEO,
+      Josa::Ideonga => IDEONGA,
+      Josa::Ine => INE,
+      Josa::Iradeunji => IRADEUNJI,
+      Josa::Iranda => IRANDA,
+      Josa::Irani => IRANI,
+      Josa::Iramyeonseo => IRAMYEONSEO,
+      Josa::Iragon => IRAGON,
+      Josa::Iroda => IRODA,
+      Josa::Iolsida => IOLSIDA,
+      Josa::Irandeul => IRANDEUL,
+      Josa::Irageona => IRAGEONA,
+      Josa::Iragiboda => IRAGIBODA,
+      Josa::Iya => IYA,
+      Josa::Iraya => IRAYA,
+      Josa::Igie => IGIE,
+      Josa::Do => DO,
+      Josa::Man => MAN,
+      Josa::Kkaji => KKAJI,
+      Josa::Buteo => BUTEO,
+      Josa::Mada => MADA,
+      Josa::E => E_,
+      Josa::Eseo => ESEO,
+      Josa::Ege => EGE,
+      Josa::Hante => HANTE,
+      Josa::Cheoreom => CHEOREOM,
+      Josa::Boda => BODA,
+      Josa::Bakke => BAKKE,
+      Josa::Kkeseo => KKESEO,
+      Josa::Kke => KKE
     }
   }
 
@@ -254,13 +1387,133 @@ impl Josa {
       Josa::IGa => "이(가)",
       Josa::EulReul => "을(를)",
       Josa::GwaWa => "와(과)",
+      Josa::AYa => "아(야)",
       Josa::I => "(이)",
-      Josa::Eu => "(으)"
+      Josa::Eu => "(으)",
+      Josa::EuRo => "(으)로",
+      Josa::EuRoSeo => "(으)로서",
+      Josa::EuRoSsi => "(으)로써",
+      Josa::EuRoButeo => "(으)로부터",
+      Josa::Ida => "(이)다",
+      Josa::Ina => "(이)나",
+      Josa::Iran => "(이)란",
+      Josa::Ideunga => "(이)든가",
+      Josa::Inama => "(이)나마",
+      Josa::Iyamallo => "(이)야말로",
+      Josa::Irang => "(이)랑",
+      Josa::Iyeo => "(이)여",
+      Josa::Imyeo => "(이)며",
+      Josa::Ieyo => "(이)에요",
+      Josa::Ieotda => "(이)었다",
+      Josa::Irago => "(이)라고",
+      Josa::Iraneun => "(이)라는",
+      Josa::Irado => "(이)라도",
+      Josa::Iramyeon => "(이)라면",
+      Josa::Ideunji => "(이)든지",
+      Josa::Inikka => "(이)니까",
+      Josa::Ijiman => "(이)지만",
+      Josa::Iraseo => "(이)라서",
+      Josa::Ija => IJA,
+      Josa::Isiyeo => "(이)시여",
+      Josa::EunKeonyeong => "은(는)커녕",
+      Josa::EuRoUi => "(으)로의",
+      Josa::GwaUi => "와(과)의",
+      Josa::GwaNeun => "와(과)는",
+      Josa::GwaDo => "와(과)도",
+      Josa::Igo => "(이)고",
+      Josa::Igeona => "(이)거나",
+      Josa::Idoe => "(이)되",
+      Josa::Inya => "(이)냐",
+      Josa::Imyeon => "(이)면",
+      Josa::Ini => "(이)니",
+      Josa::Ieoseo => "(이)어서",
+      Josa::Ideonga => "(이)던가",
+      Josa::Ine => "(이)네",
+      Josa::Iradeunji => "(이)라든지",
+      Josa::Iranda => "(이)란다",
+      Josa::Irani => "(이)라니",
+      Josa::Iramyeonseo => "(이)라면서",
+      Josa::Iragon => "(이)라곤",
+      Josa::Iroda => "(이)로다",
+      Josa::Iolsida => "(이)올시다",
+      Josa::Irandeul => "(이)란들",
+      Josa::Irageona => "(이)라거나",
+      Josa::Iragiboda => "(이)라기보다",
+      Josa::Iya => "(이)야",
+      Josa::Iraya => "(이)라야",
+      Josa::Igie => "(이)기에",
+      Josa::Do => DO,
+      Josa::Man => MAN,
+      Josa::Kkaji => KKAJI,
+      Josa::Buteo => BUTEO,
+      Josa::Mada => MADA,
+      Josa::E => E_,
+      Josa::Eseo => ESEO,
+      Josa::Ege => EGE,
+      Josa::Hante => HANTE,
+      Josa::Cheoreom => CHEOREOM,
+      Josa::Boda => BODA,
+      Josa::Bakke => BAKKE,
+      Josa::Kkeseo => KKESEO,
+      Josa::Kke => KKE
     }
   }
 }
 
 
+/// A [`Josa`](enum.Josa.html) followed by an invariant tail, created by [`Josa::then`](enum.Josa.html#method.then).
+#[derive(Clone, Copy)]
+pub struct ComposedJosa<'a> {
+  head: Josa,
+  tail: &'a str
+}
+
+impl ComposedJosa<'_> {
+  /// Select appropriate composed josa for a string.
+  ///
+  /// # Errors
+  /// Same as [`select`](fn.select.html).
+  ///
+  /// # Example
+  /// ```
+  /// use josa::EunNeun;
+  /// # use josa::Error;
+  ///
+  /// assert_eq!(EunNeun.then("커녕").select("밥")?, "은커녕");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn select(self, noun: &str) -> Result<String> {
+    Ok(format!("{}{}", select(noun, self.head)?, self.tail))
+  }
+
+  /// Append this composed josa onto the end of a [`String`],
+  /// just like [`push_josa`](trait.JosaExt.html#tymethod.push_josa) does for a [`Josa`].
+  ///
+  /// Note that it has [edge cases](index.html#edge-cases).
+  ///
+  /// # Example
+  /// ```
+  /// use josa::EuRo;
+  ///
+  /// let mut hand = "손".to_owned();
+  /// EuRo.then("는").push_to(&mut hand);
+  ///
+  /// assert_eq!(hand, "손으로는");
+  /// ```
+  ///
+  /// [`Josa`]: enum.Josa.html
+  /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+  pub fn push_to(self, string: &mut String) {
+    Selector::new().push_josa(string, self);
+  }
+}
+
+impl From<Josa> for ComposedJosa<'_> {
+  fn from(josa: Josa) -> Self {
+    josa.then("")
+  }
+}
+
 /// Select appropriate josa for a string.
 ///
 /// It is useful when you are trying to append a josa to formatted text such as `<span>고양이</span>`.
@@ -281,8 +1534,9 @@ impl Josa {
 /// ```
 ///
 /// # Errors
-/// If given String is an empty String
-/// or the last character is not a Haugul Syllable,
+/// If given String is an empty String, possibly followed by whitespace, invisible characters,
+/// punctuation, symbols, closing marks or emoji,
+/// or the last character is neither a Haugul Syllable, a jamo, a digit, nor a part of an acronym,
 /// it returns [`Error`](enum.Error.html).
 ///
 /// # Example
@@ -295,9 +1549,104 @@ impl Josa {
 /// # Ok::<(), Error>(())
 /// ```
 pub fn select(noun: &str, josa: Josa) -> Result<&'static str> {
-  josa.select(
-    noun.chars().last().ok_or(Error::EmptyStr)?
-  )
+  Selector::new().select(noun, josa)
+}
+
+/// Select appropriate josa by the last Hangul Syllable found anywhere in a string.
+///
+/// Instead of skipping known trailing characters like [`select`](fn.select.html),
+/// it walks backwards past everything that is not a Hangul Syllable,
+/// which handles strings like `고양이 (NEW!)` or `결과값)` uniformly.
+/// This is a heuristic: it is wrong when the last word is not Hangul, e.g. `새 iPhone`.
+///
+/// To route [`push_josa`](trait.JosaExt.html#tymethod.push_josa) through it,
+/// use [`Selector::last_hangul`](struct.Selector.html#method.last_hangul).
+///
+/// # Errors
+/// If the string has no Hangul Syllable, it returns [`Error`](enum.Error.html).
+///
+/// # Example
+/// ```
+/// use josa::select_last_hangul;
+/// use josa::{EunNeun, IGa};
+/// # use josa::Error;
+///
+/// assert_eq!(select_last_hangul("고양이 (NEW!)", IGa)?, "가");
+/// assert_eq!(select_last_hangul("결과값)", EunNeun)?, "은");
+/// // Wrong, since iPhone is read as 아이폰
+/// assert_eq!(select_last_hangul("새 iPhone", IGa)?, "가");
+/// # Ok::<(), Error>(())
+/// ```
+pub fn select_last_hangul(noun: &str, josa: Josa) -> Result<&'static str> {
+  Selector::new().last_hangul(true).select(noun, josa)
+}
+
+/// Select appropriate josa for the text of HTML, such as `<b>고양이</b>`.
+///
+/// It walks past tags and comments, and decodes entity references such as `&#44592;` and `&nbsp;`,
+/// so that josa is selected by the last character of the text.
+///
+/// # Errors
+/// If the HTML has an unclosed tag or comment, or an invalid character reference,
+/// it returns [`Error::MalformedHtml`](enum.Error.html#variant.MalformedHtml).
+/// Otherwise, it returns [`Error`](enum.Error.html) for the text just like [`select`](fn.select.html).
+///
+/// # Example
+/// ```
+/// use josa::select_html;
+/// use josa::{Eu, IGa};
+/// # use josa::Error;
+///
+/// assert_eq!(select_html("<b>손</b>", Eu)?, "으");
+/// assert_eq!(select_html("고양이&nbsp;", IGa)?, "가");
+/// assert_eq!(select_html("<em>&#44608;</em>", IGa)?, "이");
+/// # Ok::<(), Error>(())
+/// ```
+pub fn select_html(html: &str, josa: Josa) -> Result<&'static str> {
+  Selector::new().select_html(html, josa)
+}
+
+/// Select appropriate subject josa for a string, 께서 if the subject is honorific.
+///
+/// See [`Josa::subject`](enum.Josa.html#method.subject).
+///
+/// # Errors
+/// If given String is an empty String, it returns [`Error`](enum.Error.html).
+/// For a non-honorific subject, it also returns [`Error`](enum.Error.html)
+/// if the last character is not a Hangul Syllable, just like [`select`](fn.select.html).
+///
+/// # Example
+/// ```
+/// use josa::select_subject;
+/// # use josa::Error;
+///
+/// assert_eq!(select_subject("선생님", true)?, "께서");
+/// assert_eq!(select_subject("학생", false)?, "이");
+/// # Ok::<(), Error>(())
+/// ```
+pub fn select_subject(noun: &str, honorific: bool) -> Result<&'static str> {
+  select(noun, Josa::subject(honorific))
+}
+
+/// Select appropriate dative josa for a string, 께 if the referent is honorific.
+///
+/// See [`Josa::dative`](enum.Josa.html#method.dative).
+///
+/// # Errors
+/// If given String is an empty String, it returns [`Error`](enum.Error.html).
+///
+/// # Example
+/// ```
+/// use josa::{select_dative, Register};
+/// # use josa::Error;
+///
+/// assert_eq!(select_dative("선생님", true, Register::Formal)?, "께");
+/// assert_eq!(select_dative("동생", false, Register::Formal)?, "에게");
+/// assert_eq!(select_dative("동생", false, Register::Casual)?, "한테");
+/// # Ok::<(), Error>(())
+/// ```
+pub fn select_dative(noun: &str, honorific: bool, register: Register) -> Result<&'static str> {
+  select(noun, Josa::dative(honorific, register))
 }
 
 /// An extension trait to add [`push_josa`](trait.JosaExt.html#tymethod.push_josa) method to [`String`](https://doc.rust-lang.org/std/string/struct.String.html).
@@ -309,19 +1658,14 @@ impl JosaExt for String {
   /// Append a given [`Josa`] onto the end of this [`String`].
   ///
   /// Note that it has [edge cases](index.html#edge-cases).
+  /// To append a [`ComposedJosa`], use [`ComposedJosa::push_to`] or `+=`.
   /// 
   /// [`Josa`]: enum.Josa.html
+  /// [`ComposedJosa`]: struct.ComposedJosa.html
+  /// [`ComposedJosa::push_to`]: struct.ComposedJosa.html#method.push_to
   /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
   fn push_josa(&mut self, josa: Josa) {
-    let josa = match select(self, josa) {
-      Ok(josa) => josa,
-      Err(err) => match err {
-        Error::EmptyStr => "",
-        Error::ParseSyllable(_) => josa.both()
-      }
-    };
-
-    self.push_str(josa);
+    Selector::new().push_josa(self, josa);
   }
 }
 
@@ -339,3 +1683,18 @@ impl AddAssign<Josa> for String {
     self.push_josa(josa);
   }
 }
+
+impl Add<ComposedJosa<'_>> for String {
+  type Output = String;
+
+  fn add(mut self, josa: ComposedJosa<'_>) -> String {
+    josa.push_to(&mut self);
+    self
+  }
+}
+
+impl AddAssign<ComposedJosa<'_>> for String {
+  fn add_assign(&mut self, josa: ComposedJosa<'_>) {
+    josa.push_to(self);
+  }
+}
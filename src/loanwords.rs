@@ -0,0 +1,35 @@
+use std::convert::TryFrom;
+
+use crate::{latin, JongseongKind};
+
+// Table of English loanwords and their established Korean transliterations,
+// sorted by the English word, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/loanwords.rs"));
+
+/// Test whether the trailing word of a string is in the loanword dictionary,
+/// which is consulted for strings ending in Latin letters.
+///
+/// It is useful to log words missing from the dictionary.
+///
+/// # Example
+/// ```
+/// use josa::is_known_loanword;
+///
+/// assert!(is_known_loanword("Orange"));
+/// assert!(is_known_loanword("나의 internet"));
+/// assert!(!is_known_loanword("josa"));
+/// ```
+pub fn is_known_loanword(noun: &str) -> bool {
+  lookup(noun).is_some()
+}
+
+// Looks up the trailing word of a string in the dictionary, case-insensitively
+pub(crate) fn lookup(noun: &str) -> Option<JongseongKind> {
+  let word = latin::word(noun).to_ascii_lowercase();
+
+  LOANWORDS
+    .binary_search_by(|&(loanword, _)| loanword.cmp(word.as_str()))
+    .ok()
+    .and_then(|i| LOANWORDS[i].1.chars().last())
+    .and_then(|c| JongseongKind::try_from(c).ok())
+}
@@ -0,0 +1,32 @@
+use crate::trailing;
+
+// Characters of Markdown emphasis, strikethrough and code span markers
+fn is_marker(c: char) -> bool {
+  matches!(c, '*' | '_' | '`' | '~')
+}
+
+// Splits a string ending in a balanced Markdown marker such as **칼** or `println`,
+// optionally followed by characters that josa selection looks past,
+// into the enclosed text and where the closing marker ends.
+// Returns `None` if the string does not end in a balanced marker.
+pub(crate) fn split(noun: &str) -> Option<(&str, usize)> {
+  let end = noun
+    .trim_end_matches(|c| !is_marker(c) && trailing::class(c).is_some())
+    .len();
+  let marker = noun[..end].chars().last().filter(|&c| is_marker(c))?;
+  let close = noun[..end].trim_end_matches(marker).len();
+  let run = &noun[close..end];
+  let open = noun[..close].rfind(run)?;
+  let text = &noun[open + run.len()..close];
+
+  let balanced = !text.is_empty()
+    && !text.starts_with(marker)
+    && !text.ends_with(marker)
+    && !noun[..open].ends_with(marker);
+
+  if balanced {
+    Some((text, end))
+  } else {
+    None
+  }
+}
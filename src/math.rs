@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use crate::width;
+
+// Folds a mathematical alphanumeric symbol such as 𝐀 (U+1D400) or 𝟑 (U+1D7D1)
+// into its ASCII letter or digit, and leaves other characters as is.
+// Styled letters missing from the block, such as ℎ and ℝ, are in Letterlike Symbols.
+pub(crate) fn fold(c: char) -> char {
+  let code = c as u32;
+
+  match c {
+    // Letters in 13 styles, each of A to Z followed by a to z
+    '\u{1d400}'..='\u{1d6a3}' => match (code - 0x1d400) % 52 {
+      offset @ 0..=25 => char::from_u32(0x41 + offset).unwrap_or(c),
+      offset => char::from_u32(0x61 + offset - 26).unwrap_or(c)
+    },
+    // Digits in 5 styles, each of 0 to 9
+    '\u{1d7ce}'..='\u{1d7ff}' => char::from_digit((code - 0x1d7ce) % 10, 10).unwrap_or(c),
+    'ℬ' => 'B',
+    'ℰ' => 'E',
+    'ℱ' => 'F',
+    'ℋ' | 'ℌ' | 'ℍ' => 'H',
+    'ℐ' | 'ℑ' => 'I',
+    'ℒ' => 'L',
+    'ℳ' => 'M',
+    'ℛ' | 'ℜ' | 'ℝ' => 'R',
+    'ℂ' | 'ℭ' => 'C',
+    'ℕ' => 'N',
+    'ℙ' => 'P',
+    'ℚ' => 'Q',
+    'ℤ' | 'ℨ' => 'Z',
+    'ℯ' => 'e',
+    'ℊ' => 'g',
+    'ℎ' => 'h',
+    'ℴ' => 'o',
+    _ => c
+  }
+}
+
+// Folds the mathematical alphanumeric symbols in the last word of a string into their ASCII characters
+pub(crate) fn fold_str(noun: &str) -> Cow<'_, str> {
+  width::fold_last_word(noun, fold)
+}
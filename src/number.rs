@@ -0,0 +1,184 @@
+use crate::{Error, JongseongKind, Result};
+
+// Numbers longer than this are not read, since units above 경 are rarely used
+const MAX_DIGITS: usize = 20;
+
+// Code points of zero of every decimal digit set in Unicode category Nd, e.g. U+0660 (٠),
+// each followed by the digits from one to nine
+static ZEROS: [u32; 66] = [
+  0x30, 0x660, 0x6f0, 0x7c0, 0x966, 0x9e6, 0xa66, 0xae6, 0xb66, 0xbe6,
+  0xc66, 0xce6, 0xd66, 0xde6, 0xe50, 0xed0, 0xf20, 0x1040, 0x1090, 0x17e0,
+  0x1810, 0x1946, 0x19d0, 0x1a80, 0x1a90, 0x1b50, 0x1bb0, 0x1c40, 0x1c50, 0xa620,
+  0xa8d0, 0xa900, 0xa9d0, 0xa9f0, 0xaa50, 0xabf0, 0xff10, 0x104a0, 0x10d30, 0x11066,
+  0x110f0, 0x11136, 0x111d0, 0x112f0, 0x11450, 0x114d0, 0x11650, 0x116c0, 0x11730, 0x118e0,
+  0x11950, 0x11c50, 0x11d50, 0x11da0, 0x16a60, 0x16ac0, 0x16b50, 0x1d7ce, 0x1d7d8, 0x1d7e2,
+  0x1d7ec, 0x1d7f6, 0x1e140, 0x1e2f0, 0x1e950, 0x1fbf0
+];
+
+// Maps a decimal digit of any script to its ASCII digit, e.g. ٣ (U+0663) as 3.
+// `char::to_digit` only accepts ASCII digits, hence the table.
+fn decimal(c: char) -> Option<char> {
+  let code = c as u32;
+  let zero = *ZEROS.iter().rev().find(|&&zero| zero <= code)?;
+
+  match code - zero {
+    offset @ 0..=9 => char::from_digit(offset, 10),
+    _ => None
+  }
+}
+
+// Maps an enclosed number to its value, e.g. ② (U+2461) as 2 and ⑽ (U+247D) as 10
+fn enclosed(c: char) -> Option<u32> {
+  let code = c as u32;
+
+  match c {
+    // Circled, parenthesized and full stop numbers from 1 to 20
+    '\u{2460}'..='\u{249b}' => Some((code - 0x2460) % 20 + 1),
+    '\u{24ea}' | '\u{24ff}' => Some(0),
+    // Negative circled numbers from 11 to 20
+    '\u{24eb}'..='\u{24f4}' => Some(code - 0x24eb + 11),
+    // Double circled numbers from 1 to 10
+    '\u{24f5}'..='\u{24fe}' => Some(code - 0x24f5 + 1),
+    // Circled numbers from 21 to 35, and from 36 to 50
+    '\u{3251}'..='\u{325f}' => Some(code - 0x3251 + 21),
+    '\u{32b1}'..='\u{32bf}' => Some(code - 0x32b1 + 36),
+    _ => None
+  }
+}
+
+// Maps a superscript or subscript digit to its ASCII digit, e.g. ³ (U+00B3) as 3
+fn script(c: char) -> Option<char> {
+  match c {
+    '\u{b9}' => Some('1'),
+    '\u{b2}' => Some('2'),
+    '\u{b3}' => Some('3'),
+    '\u{2070}' | '\u{2074}'..='\u{2079}' => char::from_digit(c as u32 - 0x2070, 10),
+    '\u{2080}'..='\u{2089}' => char::from_digit(c as u32 - 0x2080, 10),
+    _ => None
+  }
+}
+
+// Superscript and subscript signs and parentheses, e.g. ⁻ (U+207B) and ⁾ (U+207E)
+fn is_script_sign(c: char) -> bool {
+  matches!(c, '\u{207a}'..='\u{207e}' | '\u{208a}'..='\u{208e}')
+}
+
+// Returns the base character of a keycap sequence at the end of a string,
+// e.g. 1 of 1️⃣ (1, U+FE0F, U+20E3), where the variation selector is optional
+fn keycap(noun: &str) -> Option<char> {
+  let base = noun.strip_suffix('\u{20e3}')?;
+  let base = base.strip_suffix('\u{fe0f}').unwrap_or(base);
+
+  match base.chars().last()? {
+    c @ ('0'..='9' | '#' | '*') => Some(c),
+    _ => None
+  }
+}
+
+// Korean reading of each digit: 영, 일, 이, 삼, 사, 오, 육, 칠, 팔, 구
+fn digit(c: char) -> Option<JongseongKind> {
+  match c {
+    '0' | '3' | '6' => Some(JongseongKind::Closed),
+    '1' | '7' | '8' => Some(JongseongKind::Rieul),
+    '2' | '4' | '5' | '9' => Some(JongseongKind::Open),
+    _ => None
+  }
+}
+
+// Korean reading of the last unit of 10^exponent: 십, 백, 천, 만, 억, 조, 경
+fn unit(exponent: usize) -> JongseongKind {
+  match exponent {
+    12..=15 => JongseongKind::Open,
+    _ => JongseongKind::Closed
+  }
+}
+
+// Classifies a string ending in a number by the last syllable of its Korean reading,
+// e.g. 10 as 십, 100000 as 십만.
+// Digits after a decimal point are read one by one, e.g. 4.5 as 사 점 오.
+// An enclosed number such as ⑩ is read as the number it encloses.
+// A keycap such as 1️⃣ is read as its digit, and a keycap of # or * is not read.
+// Superscript and subscript digits such as ³ in 10³ are read as a number of their own.
+// Superscript and subscript signs and parentheses at the end are skipped after any digit, e.g. ⁻ in 10⁻.
+// Returns `None` if the string does not end with a digit.
+pub(crate) fn classify(noun: &str) -> Option<Result<JongseongKind>> {
+  if let Some(base) = keycap(noun) {
+    return match base {
+      '#' | '*' => Some(Err(Error::ParseSyllable(base))),
+      _ => classify(&base.to_string())
+    };
+  }
+
+  if let Some(number) = noun.chars().last().and_then(enclosed) {
+    return classify(&number.to_string());
+  }
+
+  let noun = noun.trim_end_matches(is_script_sign);
+  let mut scripts: Vec<char> = noun
+    .chars()
+    .rev()
+    .map_while(script)
+    .collect();
+
+  if !scripts.is_empty() {
+    scripts.reverse();
+    return classify(&scripts.into_iter().collect::<String>());
+  }
+
+  let mut chars = noun.chars().rev().peekable();
+  let mut last = None;
+  let mut count = 0;
+  let mut zeros = 0;
+  let mut stop = None;
+
+  while let Some(c) = chars.next() {
+    if let Some(c) = decimal(c) {
+      last.get_or_insert(c);
+
+      if c == '0' && zeros == count {
+        zeros += 1;
+      }
+
+      count += 1;
+    } else if !(is_separator(c) && count > 0 && is_digit(chars.peek())) {
+      stop = Some(c);
+      break;
+    }
+  }
+
+  let last = last?;
+
+  if stop == Some('.') && is_digit(chars.peek()) {
+    return Some(Ok(digit(last)?));
+  }
+
+  if count > MAX_DIGITS {
+    return Some(Err(Error::ParseSyllable(last)));
+  }
+
+  Some(Ok(match zeros {
+    0 => digit(last)?,
+    _ if zeros == count => digit('0')?,
+    _ => unit(zeros)
+  }))
+}
+
+// Returns where the number at the end of a string starts, including separators and decimal points,
+// e.g. 3 for $1,000.5
+pub(crate) fn start(noun: &str) -> usize {
+  noun
+    .char_indices()
+    .rev()
+    .take_while(|&(_, c)| decimal(c).is_some() || is_separator(c) || c == '.')
+    .last()
+    .map_or(noun.len(), |(i, _)| i)
+}
+
+fn is_digit(c: Option<&char>) -> bool {
+  matches!(c, Some(&c) if decimal(c).is_some())
+}
+
+// Thousands separators: comma, thin space and narrow no-break space
+fn is_separator(c: char) -> bool {
+  matches!(c, ',' | '\u{2009}' | '\u{202F}')
+}
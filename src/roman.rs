@@ -0,0 +1,96 @@
+use crate::{number, JongseongKind, Result};
+
+// Roman numerals spelled by the code points from U+2160 (Ⅰ) to U+216F (Ⅿ),
+// and by the lowercase ones from U+2170 (ⅰ) to U+217F (ⅿ)
+const NUMBER_FORMS: [&str; 16] = [
+  "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "L", "C", "D", "M"
+];
+
+// Letters of Roman numerals from the largest, along with the subtractive pairs
+const LETTERS: [(&str, u32); 13] = [
+  ("M", 1000),
+  ("CM", 900),
+  ("D", 500),
+  ("CD", 400),
+  ("C", 100),
+  ("XC", 90),
+  ("L", 50),
+  ("XL", 40),
+  ("X", 10),
+  ("IX", 9),
+  ("V", 5),
+  ("IV", 4),
+  ("I", 1)
+];
+
+// Spells out a Roman numeral code point in ASCII, e.g. Ⅻ as XII
+fn spell(c: char) -> Option<&'static str> {
+  match c {
+    '\u{2160}'..='\u{216f}' => Some(NUMBER_FORMS[c as usize - 0x2160]),
+    '\u{2170}'..='\u{217f}' => Some(NUMBER_FORMS[c as usize - 0x2170]),
+    _ => None
+  }
+}
+
+// Parses a Roman numeral in its canonical form, e.g. XIV as 14.
+// Returns `None` for a malformed numeral such as IIII or VX.
+fn parse(numeral: &str) -> Option<u32> {
+  let mut rest = numeral;
+  let mut value = 0;
+
+  for &(letters, letters_value) in LETTERS.iter() {
+    let mut count = 0;
+
+    while rest.starts_with(letters) && count < 3 {
+      rest = &rest[letters.len()..];
+      value += letters_value;
+      count += 1;
+    }
+  }
+
+  if rest.is_empty() && value > 0 && spell_out(value) == numeral {
+    Some(value)
+  } else {
+    None
+  }
+}
+
+// Writes a number as a Roman numeral in its canonical form, e.g. 14 as XIV
+fn spell_out(mut value: u32) -> String {
+  let mut numeral = String::new();
+
+  for &(letters, letters_value) in LETTERS.iter() {
+    while value >= letters_value {
+      numeral.push_str(letters);
+      value -= letters_value;
+    }
+  }
+
+  numeral
+}
+
+// Classifies a string ending in a Roman numeral by the Korean reading of its value,
+// e.g. Ⅶ as 칠 and III as 삼.
+// An ASCII numeral is only read as a separate word of I, V and X,
+// and a single letter only if `single` is set, since it may be an English word such as I.
+// Returns `None` if the string does not end with a Roman numeral.
+pub(crate) fn classify(noun: &str, single: bool) -> Option<Result<JongseongKind>> {
+  let forms: Vec<&str> = noun.chars().rev().map_while(spell).collect();
+
+  if let Some(&last) = forms.first() {
+    let numeral: String = forms.iter().rev().copied().collect();
+    let value = parse(&numeral).or_else(|| parse(last))?;
+
+    return number::classify(&value.to_string());
+  }
+
+  let len = noun.chars().rev().take_while(|c| matches!(c, 'I' | 'V' | 'X')).count();
+  let start = noun.len() - len;
+  let separate = !matches!(noun[..start].chars().last(), Some(c) if c.is_alphanumeric());
+
+  if len == 0 || !separate || (len == 1 && !single) {
+    return None;
+  }
+
+  number::classify(&parse(&noun[start..])?.to_string())
+}
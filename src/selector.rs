@@ -0,0 +1,785 @@
+use std::convert::TryFrom;
+
+use hangul::HangulExt;
+
+use crate::trailing::{self, Class};
+#[cfg(feature = "grapheme")]
+use std::borrow::Cow;
+use crate::{currency, greek, html, jamo, latin, markdown, math, number, roman, unit, width, ComposedJosa, Error, JongseongKind, Josa, Result};
+#[cfg(feature = "grapheme")]
+use crate::grapheme;
+#[cfg(feature = "hanja")]
+use crate::hanja;
+#[cfg(feature = "loanwords")]
+use crate::loanwords;
+#[cfg(feature = "yethangul")]
+use crate::yethangul;
+
+/// A configurable josa selector.
+///
+/// [`select`](fn.select.html) and [`push_josa`](trait.JosaExt.html#tymethod.push_josa)
+/// behave the same as a `Selector` with default options.
+/// Every switch is opt-in, so you can enable only what you need:
+///
+/// ```
+/// use josa::{Selector, IGa};
+///
+/// let selector = Selector::new().pronoun_contractions(true);
+///
+/// let mut me = "나".to_owned();
+/// selector.push_josa(&mut me, IGa);
+///
+/// assert_eq!(me, "내가");
+/// ```
+#[derive(Clone)]
+pub struct Selector {
+  pronoun_contractions: bool,
+  word_boundary: bool,
+  contractions: bool,
+  latin_heuristic: bool,
+  romanization: bool,
+  greek_letters: bool,
+  punctuation: Placement,
+  symbols: Placement,
+  parenthetical_contents: bool,
+  emoji: Placement,
+  jamo_runs: JamoRun,
+  single_roman_numerals: bool,
+  units: Vec<(String, String)>,
+  markdown: bool,
+  last_hangul: bool,
+  #[cfg(feature = "grapheme")]
+  grapheme_aware: bool
+}
+
+/// Where [`push_josa`](struct.Selector.html#method.push_josa) places josa
+/// relative to the trailing characters that josa selection looks past.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placement {
+  /// Insert josa before the characters, e.g. 고양이가...
+  Before,
+  /// Append josa after the characters, e.g. 고양이...가
+  After
+}
+
+/// How a trailing run of jamo in internet slang, such as ㅋㅋ and ㅠㅠ, is read.
+///
+/// See [`Selector::jamo_runs`](struct.Selector.html#method.jamo_runs).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JamoRun {
+  /// Skip the run, and select josa by the character before it, e.g. 고마워ㅠㅠ는
+  Skip,
+  /// Read the last jamo as it is pronounced, e.g. ㅋ as 크 and ㅇ as 응
+  Pronounce
+}
+
+impl Default for Selector {
+  fn default() -> Selector {
+    Selector {
+      pronoun_contractions: false,
+      word_boundary: false,
+      contractions: false,
+      latin_heuristic: false,
+      romanization: false,
+      greek_letters: false,
+      punctuation: Placement::Before,
+      symbols: Placement::After,
+      parenthetical_contents: false,
+      emoji: Placement::After,
+      jamo_runs: JamoRun::Skip,
+      single_roman_numerals: false,
+      units: Vec::new(),
+      markdown: false,
+      last_hangul: false,
+      #[cfg(feature = "grapheme")]
+      grapheme_aware: false
+    }
+  }
+}
+
+impl Selector {
+  /// Create a selector with default options.
+  pub fn new() -> Selector {
+    Selector::default()
+  }
+
+  /// Rewrite pronouns 나, 저, 너 and 누구 along with 이/가, into 내가, 제가, 네가 and 누가.
+  ///
+  /// Only [`push_josa`](#method.push_josa) rewrites the pronoun,
+  /// since [`select`](#method.select) returns a josa only.
+  /// By default, it is triggered only when the whole string is the pronoun.
+  /// See [`word_boundary`](#method.word_boundary) to match the last word instead.
+  pub fn pronoun_contractions(mut self, enabled: bool) -> Selector {
+    self.pronoun_contractions = enabled;
+    self
+  }
+
+  /// Match pronouns against the last whitespace-separated word instead of the whole string.
+  ///
+  /// ```
+  /// use josa::{Selector, IGa};
+  ///
+  /// let selector = Selector::new().pronoun_contractions(true).word_boundary(true);
+  ///
+  /// let mut sentence = "그러니까 너".to_owned();
+  /// selector.push_josa(&mut sentence, IGa);
+  ///
+  /// assert_eq!(sentence, "그러니까 네가");
+  /// ```
+  pub fn word_boundary(mut self, enabled: bool) -> Selector {
+    self.word_boundary = enabled;
+    self
+  }
+
+  /// Fuse colloquial contractions such as 나는 → 난, 나를 → 날, 너는 → 넌, 저는 → 전, 것은 → 건, 것이 → 게.
+  ///
+  /// It only applies when the last whitespace-separated word is exactly the contracted word,
+  /// so that words merely ending in 나, such as 하나, are left intact.
+  /// Like [`pronoun_contractions`](#method.pronoun_contractions), only [`push_josa`](#method.push_josa) is affected.
+  ///
+  /// ```
+  /// use josa::{Selector, EunNeun, EulReul};
+  ///
+  /// let selector = Selector::new().contractions(true);
+  ///
+  /// let mut me = "나".to_owned();
+  /// let mut thing = "그 것".to_owned();
+  ///
+  /// selector.push_josa(&mut me, EunNeun);
+  /// selector.push_josa(&mut thing, EulReul);
+  ///
+  /// assert_eq!(me, "난");
+  /// assert_eq!(thing, "그 걸");
+  /// ```
+  pub fn contractions(mut self, enabled: bool) -> Selector {
+    self.contractions = enabled;
+    self
+  }
+
+  /// Guess josa for a string ending in Latin letters, by the typical Korean reading of its last letters.
+  ///
+  /// For example, words ending in b, ck, k, g, m, n, ng, p, t are treated as closed,
+  /// l and le as ㄹ, and vowels as open.
+  /// It is only a heuristic, so it can be wrong for some words such as orange (오렌지).
+  ///
+  /// ```
+  /// use josa::{Selector, IGa, EunNeun, EuRo};
+  /// # use josa::Error;
+  ///
+  /// let selector = Selector::new().latin_heuristic(true);
+  ///
+  /// assert_eq!(selector.select("Google", IGa)?, "이");
+  /// assert_eq!(selector.select("Coffee", EunNeun)?, "는");
+  /// assert_eq!(selector.select("Excel", EuRo)?, "로");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn latin_heuristic(mut self, enabled: bool) -> Selector {
+    self.latin_heuristic = enabled;
+    self
+  }
+
+  /// Guess josa for a string ending in a romanized Korean name, by its last romanized letter.
+  ///
+  /// Names ending in k, t, p, m, n or ng are treated as closed, l as ㄹ, and vowels as open,
+  /// e.g. Minseok (민석), Seul (슬), Jiwoo (지우).
+  /// Unlike [`latin_heuristic`](#method.latin_heuristic), it doesn't assume an English reading,
+  /// so Park is read as 박 instead of 파크.
+  /// It takes precedence over the loanword dictionary, acronyms and the English heuristic,
+  /// so names in capitals such as YUJIN are also read as names.
+  ///
+  /// ```
+  /// use josa::{Selector, EunNeun, EuRo, IGa};
+  /// # use josa::Error;
+  ///
+  /// let selector = Selector::new().romanization(true);
+  ///
+  /// assert_eq!(selector.select("Yujin", EunNeun)?, "은");
+  /// assert_eq!(selector.select("Seul", EuRo)?, "로");
+  /// assert_eq!(selector.select("Jiwoo", IGa)?, "가");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn romanization(mut self, enabled: bool) -> Selector {
+    self.romanization = enabled;
+    self
+  }
+
+  /// Read a trailing Greek letter by its Korean name, e.g. β as 베타 and ε as 엡실론.
+  ///
+  /// ```
+  /// use josa::{Selector, IGa};
+  /// # use josa::Error;
+  ///
+  /// let selector = Selector::new().greek_letters(true);
+  ///
+  /// assert_eq!(selector.select("계수 β", IGa)?, "가");
+  /// assert_eq!(selector.select("ε", IGa)?, "이");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn greek_letters(mut self, enabled: bool) -> Selector {
+    self.greek_letters = enabled;
+    self
+  }
+
+  /// Place josa before or after trailing sentence punctuation, `.`, `,`, `!`, `?`, `…`, `·` and `~`.
+  ///
+  /// Josa is selected by the character before the punctuation either way.
+  /// It defaults to [`Placement::Before`](enum.Placement.html#variant.Before).
+  ///
+  /// ```
+  /// use josa::{Selector, Placement, IGa};
+  ///
+  /// let mut before = "고양이...".to_owned();
+  /// let mut after = "고양이...".to_owned();
+  ///
+  /// Selector::new().push_josa(&mut before, IGa);
+  /// Selector::new().punctuation(Placement::After).push_josa(&mut after, IGa);
+  ///
+  /// assert_eq!(before, "고양이가...");
+  /// assert_eq!(after, "고양이...가");
+  /// ```
+  pub fn punctuation(mut self, placement: Placement) -> Selector {
+    self.punctuation = placement;
+    self
+  }
+
+  /// Place josa before or after trailing trademark and reference symbols, `™`, `®`, `©`, `※`, `*`, `†` and `‡`.
+  ///
+  /// Josa is selected by the character before the symbols either way.
+  /// It defaults to [`Placement::After`](enum.Placement.html#variant.After),
+  /// since the symbols are usually a part of the name.
+  ///
+  /// ```
+  /// use josa::{Selector, Placement, EunNeun};
+  ///
+  /// let mut after = "갤럭시™".to_owned();
+  /// let mut before = "갤럭시™".to_owned();
+  ///
+  /// Selector::new().push_josa(&mut after, EunNeun);
+  /// Selector::new().symbols(Placement::Before).push_josa(&mut before, EunNeun);
+  ///
+  /// assert_eq!(after, "갤럭시™는");
+  /// assert_eq!(before, "갤럭시는™");
+  /// ```
+  pub fn symbols(mut self, placement: Placement) -> Selector {
+    self.symbols = placement;
+    self
+  }
+
+  /// Select josa by the contents of a parenthetical at the end of a string,
+  /// instead of the word before it.
+  ///
+  /// By default, a balanced parenthetical such as (주) or (가칭) is skipped,
+  /// and josa is selected by the character before the opening parenthesis.
+  /// If nothing precedes the parenthetical, its contents are used either way.
+  ///
+  /// ```
+  /// use josa::{Selector, EunNeun};
+  /// # use josa::Error;
+  ///
+  /// let selector = Selector::new().parenthetical_contents(true);
+  ///
+  /// assert_eq!(Selector::new().select("서울시(가칭)", EunNeun)?, "는");
+  /// assert_eq!(selector.select("서울시(가칭)", EunNeun)?, "은");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn parenthetical_contents(mut self, enabled: bool) -> Selector {
+    self.parenthetical_contents = enabled;
+    self
+  }
+
+  /// Place josa before or after trailing emoji.
+  ///
+  /// Josa is selected by the character before the emoji either way,
+  /// including emoji sequences joined with ZWJ and skin tone modifiers.
+  /// It defaults to [`Placement::After`](enum.Placement.html#variant.After).
+  ///
+  /// ```
+  /// use josa::{Selector, Placement, IGa};
+  ///
+  /// let mut after = "고양이🐱".to_owned();
+  /// let mut before = "고양이🐱".to_owned();
+  ///
+  /// Selector::new().push_josa(&mut after, IGa);
+  /// Selector::new().emoji(Placement::Before).push_josa(&mut before, IGa);
+  ///
+  /// assert_eq!(after, "고양이🐱가");
+  /// assert_eq!(before, "고양이가🐱");
+  /// ```
+  pub fn emoji(mut self, placement: Placement) -> Selector {
+    self.emoji = placement;
+    self
+  }
+
+  /// Choose how a trailing run of jamo in internet slang, such as ㅋㅋ and ㅠㅠ, is read.
+  ///
+  /// A run is a sequence of jamo attached to the preceding character, or of two or more jamo.
+  /// It defaults to [`JamoRun::Skip`](enum.JamoRun.html#variant.Skip).
+  /// If nothing but jamo is left, the jamo are read by their names as usual.
+  /// [`push_josa`](#method.push_josa) appends josa after the run either way.
+  ///
+  /// ```
+  /// use josa::{Selector, JamoRun, EunNeun};
+  ///
+  /// let mut skip = "고마워ㅠㅠ".to_owned();
+  /// let mut pronounce = "재밌닼ㅋㅋ".to_owned();
+  ///
+  /// Selector::new().push_josa(&mut skip, EunNeun);
+  /// Selector::new().jamo_runs(JamoRun::Pronounce).push_josa(&mut pronounce, EunNeun);
+  ///
+  /// assert_eq!(skip, "고마워ㅠㅠ는");
+  /// assert_eq!(pronounce, "재밌닼ㅋㅋ는");
+  /// ```
+  pub fn jamo_runs(mut self, mode: JamoRun) -> Selector {
+    self.jamo_runs = mode;
+    self
+  }
+
+  /// Read a single trailing letter I, V or X as a Roman numeral, instead of an English word.
+  ///
+  /// Roman numerals of two or more letters, such as II, and the code points such as Ⅶ
+  /// are read by the Korean reading of their value regardless.
+  ///
+  /// ```
+  /// use josa::{Selector, IGa};
+  /// # use josa::Error;
+  ///
+  /// let selector = Selector::new().single_roman_numerals(true);
+  ///
+  /// assert_eq!(selector.select("Mac OS X", IGa)?, "이");
+  /// assert_eq!(selector.select("록키 V", IGa)?, "가");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn single_roman_numerals(mut self, enabled: bool) -> Selector {
+    self.single_roman_numerals = enabled;
+    self
+  }
+
+  /// Add a unit of measurement read after a number, along with its Korean reading.
+  ///
+  /// Josa is selected by the last character of the reading.
+  /// Added units take precedence over the builtin ones such as kg and %,
+  /// and units with an empty reading are ignored.
+  ///
+  /// ```
+  /// use josa::{Selector, IGa};
+  /// # use josa::Error;
+  ///
+  /// let selector = Selector::new().unit("bpm", "비피엠").unit("평", "평");
+  ///
+  /// assert_eq!(selector.select("120bpm", IGa)?, "이");
+  /// assert_eq!(selector.select("32평", IGa)?, "이");
+  /// # Ok::<(), Error>(())
+  /// ```
+  pub fn unit(mut self, unit: &str, reading: &str) -> Selector {
+    self.units.push((unit.to_owned(), reading.to_owned()));
+    self
+  }
+
+  /// Select josa by the text enclosed in Markdown emphasis, strikethrough or code span markers
+  /// at the end of a string, such as **칼**, _고양이_, ~~취소~~ and `변수`.
+  ///
+  /// [`push_josa`](#method.push_josa) appends josa after the closing marker.
+  /// Unbalanced markers are treated as usual.
+  ///
+  /// ```
+  /// use josa::{Selector, Eu, EunNeun};
+  ///
+  /// let selector = Selector::new().markdown(true);
+  ///
+  /// let mut knife = "**칼**".to_owned();
+  /// let mut cancel = "~~취소~~".to_owned();
+  ///
+  /// selector.push_josa(&mut knife, Eu);
+  /// selector.push_josa(&mut cancel, EunNeun);
+  ///
+  /// assert_eq!(knife, "**칼**");
+  /// assert_eq!(cancel, "~~취소~~는");
+  /// ```
+  pub fn markdown(mut self, enabled: bool) -> Selector {
+    self.markdown = enabled;
+    self
+  }
+
+  /// Select josa by the last Hangul Syllable found anywhere in a string,
+  /// walking backwards past everything else. See [`select_last_hangul`](fn.select_last_hangul.html).
+  ///
+  /// [`push_josa`](#method.push_josa) places josa as usual after a Hangul Syllable,
+  /// and at the very end of the string otherwise.
+  ///
+  /// ```
+  /// use josa::{Selector, IGa};
+  ///
+  /// let selector = Selector::new().last_hangul(true);
+  ///
+  /// let mut apple = "사과🍎".to_owned();
+  /// let mut cat = "고양이 (NEW!)".to_owned();
+  ///
+  /// selector.push_josa(&mut apple, IGa);
+  /// selector.push_josa(&mut cat, IGa);
+  ///
+  /// assert_eq!(apple, "사과🍎가");
+  /// assert_eq!(cat, "고양이 (NEW!)가");
+  /// ```
+  pub fn last_hangul(mut self, enabled: bool) -> Selector {
+    self.last_hangul = enabled;
+    self
+  }
+
+  /// Classify a string by the base character of its last extended grapheme cluster,
+  /// instead of its last character.
+  ///
+  /// Combining marks and other characters attached to the last character are looked past,
+  /// e.g. 각́ (각, U+0301) as 각 and é written with U+0301 as e.
+  /// Decomposed Hangul is composed as usual. Requires the `grapheme` feature.
+  ///
+  /// ```
+  /// use josa::{Selector, IGa};
+  ///
+  /// let selector = Selector::new().grapheme_aware(true);
+  ///
+  /// let mut accent = "각\u{301}".to_owned();
+  /// selector.push_josa(&mut accent, IGa);
+  ///
+  /// assert_eq!(accent, "각\u{301}이");
+  /// ```
+  #[cfg(feature = "grapheme")]
+  pub fn grapheme_aware(mut self, enabled: bool) -> Selector {
+    self.grapheme_aware = enabled;
+    self
+  }
+
+  /// Select appropriate josa for a string.
+  ///
+  /// See [`select`](fn.select.html).
+  pub fn select(&self, noun: &str, josa: Josa) -> Result<&'static str> {
+    if self.last_hangul {
+      return self.select_last_hangul(noun, josa);
+    }
+
+    let noun = self.stem(noun);
+
+    if noun.is_empty() {
+      return Err(Error::EmptyStr);
+    }
+
+    if josa.is_invariant() {
+      return Ok(josa.both());
+    }
+
+    Ok(josa.select(self.classify(noun)?))
+  }
+
+  /// Select appropriate josa for the text of HTML.
+  ///
+  /// See [`select_html`](fn.select_html.html).
+  pub fn select_html(&self, html: &str, josa: Josa) -> Result<&'static str> {
+    self.select(&html::text(html)?, josa)
+  }
+
+  // Selects josa by the last Hangul Syllable in a string
+  fn select_last_hangul(&self, noun: &str, josa: Josa) -> Result<&'static str> {
+    let syllable = match noun.chars().rev().find(|c| c.is_syllable()) {
+      Some(syllable) => syllable,
+      None => {
+        return match trailing::trim(noun).chars().last() {
+          Some(c) => Err(Error::ParseSyllable(c)),
+          None => Err(Error::EmptyStr)
+        };
+      }
+    };
+
+    if josa.is_invariant() {
+      return Ok(josa.both());
+    }
+
+    Ok(josa.select(JongseongKind::try_from(syllable)?))
+  }
+
+  /// Append appropriate josa onto the end of a string.
+  ///
+  /// See [`push_josa`](trait.JosaExt.html#tymethod.push_josa).
+  pub fn push_josa<'a, J: Into<ComposedJosa<'a>>>(&self, string: &mut String, josa: J) {
+    let ComposedJosa { head, tail } = josa.into();
+    let end = self.end(string);
+    let at = self.insertion_point(string, end);
+
+    // Contractions such as 내가 take no tail, since 내가도 is ungrammatical
+    if at == end && tail.is_empty() {
+      if let Some((start, contracted)) = self.contract(&string[..end], head) {
+        string.replace_range(start..end, contracted);
+        return;
+      }
+    }
+
+    let head = match self.select(string, head) {
+      Ok(head) => head,
+      Err(err) => match err {
+        Error::EmptyStr => return,
+        Error::ParseSyllable(_) | Error::MalformedHtml => head.both()
+      }
+    };
+
+    string.insert_str(at, &format!("{}{}", head, tail));
+  }
+
+  // Classifies the ending of a non-empty string
+  pub(crate) fn classify(&self, noun: &str) -> Result<JongseongKind> {
+    // Trailing characters are looked past in fullwidth forms as well,
+    // so only the stem is folded
+    let stem = width::fold_str(self.stem(noun));
+    let stem = math::fold_str(&stem);
+    let noun: &str = &stem;
+    #[cfg(feature = "grapheme")]
+    let base = match self.grapheme_aware {
+      true => grapheme::base(noun),
+      false => Cow::Borrowed(noun)
+    };
+    #[cfg(feature = "grapheme")]
+    let noun = self.stem(&base);
+    let last = noun.chars().last().ok_or(Error::EmptyStr)?;
+
+    if let Some(kind) = currency::classify(noun) {
+      return kind;
+    }
+
+    if let Some(kind) = number::classify(noun) {
+      return kind;
+    }
+
+    if let Some(kind) = unit::classify(noun, &self.units) {
+      return kind;
+    }
+
+    if let Some(kind) = roman::classify(noun, self.single_roman_numerals) {
+      return kind;
+    }
+
+    if self.romanization {
+      if let Some(kind) = latin::romanized(noun) {
+        return Ok(kind);
+      }
+    }
+
+    #[cfg(feature = "loanwords")]
+    {
+      if let Some(kind) = loanwords::lookup(noun) {
+        return Ok(kind);
+      }
+    }
+
+    if let Some(kind) = latin::acronym(noun) {
+      return Ok(kind);
+    }
+
+    if self.greek_letters {
+      if let Some(kind) = greek::letter(last) {
+        return Ok(kind);
+      }
+    }
+
+    if self.latin_heuristic {
+      if let Some(kind) = latin::heuristic(noun) {
+        return Ok(kind);
+      }
+    }
+
+    if self.jamo_runs == JamoRun::Pronounce && jamo::run(noun).is_some() {
+      if let Some(kind) = jamo::pronounce(last) {
+        return Ok(kind);
+      }
+    }
+
+    #[cfg(feature = "hanja")]
+    {
+      if let Some(reading) = hanja::reading(last) {
+        return JongseongKind::try_from(reading);
+      }
+    }
+
+    let last = jamo::unenclose(last).unwrap_or(last);
+
+    if let Some(kind) = jamo::letter(last) {
+      return Ok(kind);
+    }
+
+    if let Some(syllable) = jamo::compose(noun) {
+      return JongseongKind::try_from(syllable);
+    }
+
+    #[cfg(feature = "yethangul")]
+    {
+      if let Some(kind) = yethangul::classify(noun) {
+        return Ok(kind);
+      }
+    }
+
+    if let Some(kind) = jamo::conjoining(last) {
+      return Ok(kind);
+    }
+
+    JongseongKind::try_from(last)
+  }
+
+  // Returns the part of a string that josa is selected by,
+  // without trailing characters and parenthetical
+  fn stem<'a>(&self, noun: &'a str) -> &'a str {
+    let mut noun = noun;
+
+    if self.markdown {
+      while let Some((text, _)) = markdown::split(noun) {
+        noun = text;
+      }
+    }
+
+    let mut stem = self.strip_parentheticals(noun);
+
+    if self.jamo_runs == JamoRun::Skip {
+      while let Some(start) = jamo::run(stem) {
+        match self.strip_parentheticals(&stem[..start]) {
+          "" => break,
+          before => stem = before
+        }
+      }
+    }
+
+    stem
+  }
+
+  // Trims trailing characters off a string, and skips a parenthetical at its end
+  // or selects by its contents, see `parenthetical_contents`.
+  // Each parenthetical is scanned once, however deeply they are nested.
+  fn strip_parentheticals<'a>(&self, noun: &'a str) -> &'a str {
+    let mut start = 0;
+    let mut end = noun.len();
+
+    'region: loop {
+      let region = &noun[start..end];
+      let (contents_end, opens) = match trailing::parentheticals(region) {
+        Some(found) => found,
+        None => return trailing::trim(region)
+      };
+
+      // From the outermost parenthetical, choose the part before it or go into its contents
+      for (level, open) in opens.iter().enumerate() {
+        if self.parenthetical_contents && open.contents {
+          continue;
+        }
+
+        let (before, text) = match level {
+          0 => (0, trailing::has_text(&region[..open.start])),
+          _ => (opens[level - 1].end, opens[level - 1].text)
+        };
+
+        if text {
+          end = start + open.start;
+          start += before;
+          continue 'region;
+        }
+      }
+
+      let innermost = opens.last().map_or(0, |open| open.end);
+      return &region[innermost..contents_end];
+    }
+  }
+
+  // Returns where the trailing characters that josa may be placed before start
+  fn end(&self, string: &str) -> usize {
+    let end = match markdown::split(string) {
+      Some((_, end)) if self.markdown => end,
+      _ => trailing::trim(string).len()
+    };
+
+    match string[..end].chars().last() {
+      Some(c) if self.last_hangul && !c.is_syllable() => string.len(),
+      _ => end
+    }
+  }
+
+  // Returns where josa goes in a string whose trailing characters start at `end`
+  fn insertion_point(&self, string: &str, end: usize) -> usize {
+    self.point(string, end, |class| self.placement(class) == Placement::Before)
+  }
+
+  // Returns where a copula ending goes in a string.
+  // It is where josa goes, except that the ending never goes after trailing whitespace.
+  pub(crate) fn copula_point(&self, string: &str) -> usize {
+    self.point(string, self.end(string), |class| match class {
+      Class::Whitespace => true,
+      class => self.placement(class) == Placement::Before
+    })
+  }
+
+  // Returns the first trailing character after `end` whose class goes after the inserted text.
+  // Punctuation before a closing quote or bracket belongs inside it, as in 《고양이!》,
+  // so the search starts after the last closing mark.
+  fn point(&self, string: &str, end: usize, before: impl Fn(Class) -> bool) -> usize {
+    let start = trailing::visible(&string[end..])
+      .filter(|&(_, c)| matches!(trailing::class(c), Some(Class::Closing)))
+      .last()
+      .map_or(end, |(i, c)| end + i + c.len_utf8());
+
+    trailing::visible(&string[start..])
+      .find(|&(_, c)| trailing::class(c).is_some_and(&before))
+      .map_or(string.len(), |(i, _)| start + i)
+  }
+
+  fn placement(&self, class: Class) -> Placement {
+    match class {
+      Class::Whitespace | Class::Closing | Class::Invisible => Placement::After,
+      Class::Punctuation => self.punctuation,
+      Class::Symbol => self.symbols,
+      Class::Emoji => self.emoji
+    }
+  }
+
+  // Returns where the contracted word starts, and the word it should be replaced with
+  fn contract(&self, string: &str, josa: Josa) -> Option<(usize, &'static str)> {
+    let start = string
+      .char_indices()
+      .rev()
+      .find(|&(_, c)| c.is_whitespace())
+      .map_or(0, |(i, c)| i + c.len_utf8());
+    let word = &string[start..];
+
+    if self.pronoun_contractions && (self.word_boundary || start == 0) {
+      if let Some(contracted) = pronoun(word, josa) {
+        return Some((start, contracted));
+      }
+    }
+
+    if self.contractions {
+      if let Some(contracted) = colloquial(word, josa) {
+        return Some((start, contracted));
+      }
+    }
+
+    None
+  }
+}
+
+// Pronouns whose stem changes in front of 가
+fn pronoun(word: &str, josa: Josa) -> Option<&'static str> {
+  match (word, josa) {
+    ("나", Josa::IGa) => Some("내가"),
+    ("저", Josa::IGa) => Some("제가"),
+    ("너", Josa::IGa) => Some("네가"),
+    ("누구", Josa::IGa) => Some("누가"),
+    _ => None
+  }
+}
+
+// Colloquial contractions of a word and a josa
+fn colloquial(word: &str, josa: Josa) -> Option<&'static str> {
+  match (word, josa) {
+    ("나", Josa::EunNeun) => Some("난"),
+    ("나", Josa::EulReul) => Some("날"),
+    ("너", Josa::EunNeun) => Some("넌"),
+    ("너", Josa::EulReul) => Some("널"),
+    ("저", Josa::EunNeun) => Some("전"),
+    ("저", Josa::EulReul) => Some("절"),
+    ("것", Josa::EunNeun) => Some("건"),
+    ("것", Josa::IGa) => Some("게"),
+    ("것", Josa::EulReul) => Some("걸"),
+    _ => None
+  }
+}
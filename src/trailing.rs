@@ -0,0 +1,190 @@
+// Characters at the end of a string that josa selection looks past.
+// `push_josa` places josa before or after them, depending on their class.
+// ANSI escape sequences among them are looked past as well, and josa goes after them.
+
+use crate::width;
+
+// Classes of characters that josa selection looks past
+#[derive(Clone, Copy)]
+pub(crate) enum Class {
+  // Spaces of every width, tabs and line breaks
+  Whitespace,
+  // Sentence punctuation
+  Punctuation,
+  // Trademark, copyright and reference marks
+  Symbol,
+  // Closing quotes and brackets
+  Closing,
+  // Emoji, along with skin tone modifiers
+  Emoji,
+  // Zero width characters, soft hyphens, bidi controls, variation selectors, tags
+  // and other format characters, which are invisible
+  Invisible
+}
+
+// Returns the class of a character that josa selection looks past,
+// or `None` if josa should be selected by the character.
+// Fullwidth forms such as ！ belong to the class of their ASCII characters.
+pub(crate) fn class(c: char) -> Option<Class> {
+  match width::fold(c) {
+    ' ' | '\t' | '\n' | '\u{b}' | '\u{c}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}' => {
+      Some(Class::Whitespace)
+    }
+    // Space separators (Zs), such as no-break space and ideographic space
+    '\u{a0}' | '\u{1680}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => {
+      Some(Class::Whitespace)
+    }
+    '.' | ',' | '!' | '?' | '…' | '·' | '~' => Some(Class::Punctuation),
+    '™' | '®' | '©' | '※' | '*' | '†' | '‡' => Some(Class::Symbol),
+    '"' | '\'' | '’' | '”' | '」' | '』' | '》' | '〉' | ')' | ']' | '}' | '｣' => Some(Class::Closing),
+    '\u{2300}'..='\u{23ff}'
+    | '\u{2600}'..='\u{27bf}'
+    | '\u{2b00}'..='\u{2bff}'
+    | '\u{1f000}'..='\u{1faff}' => Some(Class::Emoji),
+    '\u{ad}'
+    | '\u{34f}'
+    | '\u{61c}'
+    | '\u{17b4}'
+    | '\u{17b5}'
+    | '\u{180b}'..='\u{180f}'
+    | '\u{200b}'..='\u{200f}'
+    | '\u{202a}'..='\u{202e}'
+    | '\u{2060}'..='\u{2064}'
+    | '\u{2066}'..='\u{206f}'
+    | '\u{fe00}'..='\u{fe0f}'
+    | '\u{feff}'
+    | '\u{fff0}'..='\u{fff8}'
+    | '\u{1bca0}'..='\u{1bca3}'
+    | '\u{1d173}'..='\u{1d17a}'
+    | '\u{e0000}'..='\u{e0fff}' => Some(Class::Invisible),
+    _ => None
+  }
+}
+
+// Trims the characters that josa selection looks past off the end of a string,
+// along with ANSI escape sequences among them
+pub(crate) fn trim(noun: &str) -> &str {
+  let trimmed = noun.trim_end_matches(|c| class(c).is_some());
+
+  match escape(trimmed) {
+    Some(start) => trim(&trimmed[..start]),
+    None => trimmed
+  }
+}
+
+// Returns where an ANSI escape sequence at the end of a string starts, such as \x1b[0m.
+// A sequence truncated at the end of the string, such as \x1b[1, is recognized as well.
+fn escape(noun: &str) -> Option<usize> {
+  let start = noun.rfind('\u{1b}')?;
+
+  match noun.as_bytes()[start + 1..].split_first() {
+    None => Some(start),
+    Some((b'[', rest)) => {
+      let end = rest.iter().position(|b| !(0x20..=0x3f).contains(b));
+
+      match end.map(|end| &rest[end..]) {
+        None | Some([0x40..=0x7e]) => Some(start),
+        _ => None
+      }
+    }
+    _ => None
+  }
+}
+
+// States of reading ANSI escape sequences
+#[derive(Clone, Copy)]
+enum State {
+  // Not in an escape sequence
+  Text,
+  // Right after an escape character
+  Escape,
+  // In a control sequence, which starts with \x1b[
+  Control
+}
+
+// Returns the characters of a string with their indices, leaving out ANSI escape sequences
+pub(crate) fn visible(string: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+  let mut state = State::Text;
+
+  string.char_indices().filter(move |&(_, c)| {
+    let (next, visible) = match (state, c) {
+      (_, '\u{1b}') => (State::Escape, false),
+      (State::Escape, '[') => (State::Control, false),
+      (State::Control, '\u{20}'..='\u{3f}') => (State::Control, false),
+      (State::Escape, _) | (State::Control, '\u{40}'..='\u{7e}') => (State::Text, false),
+      _ => (State::Text, true)
+    };
+
+    state = next;
+    visible
+  })
+}
+
+// An opening parenthesis of a parenthetical at the end of a string
+pub(crate) struct Open {
+  // Indices of the parenthesis and right after it
+  pub(crate) start: usize,
+  pub(crate) end: usize,
+  // Whether josa can be selected by anything between the parenthesis
+  // and the next opening parenthesis nested in it, or the end of its contents
+  pub(crate) text: bool,
+  // Whether josa can be selected by anything in the contents of the parenthetical
+  pub(crate) contents: bool
+}
+
+// Finds the balanced parentheticals at the end of a string, with optional trailing characters after them,
+// in a single backward scan with a depth counter.
+// The contents may end with trailing characters as well, as in 책 (NEW!), which are skipped along with them.
+// Parentheticals nested at the end of one another, as in 책(f(x)), share the end of their contents.
+// Returns where the contents of the innermost one end, and the opening parentheses
+// from the outermost to the innermost.
+// Returns `None` if the string does not end in a balanced parenthetical.
+pub(crate) fn parentheticals(noun: &str) -> Option<(usize, Vec<Open>)> {
+  let end = trim(noun).len();
+  let rest = &noun[end..];
+
+  if !rest.chars().any(|c| width::fold(c) == ')') {
+    return None;
+  }
+
+  let mut level = rest.chars().filter(|&c| width::fold(c) == ')').count();
+  let mut depth = level;
+  let mut opens = Vec::with_capacity(level);
+  let mut text = false;
+  let mut contents = false;
+
+  for (index, c) in noun[..end].char_indices().rev() {
+    match width::fold(c) {
+      ')' => depth += 1,
+      '(' => depth -= 1,
+      _ => {
+        text |= class(c).is_none();
+        continue;
+      }
+    }
+
+    // The depth drops below every depth so far only at the parentheses the trailing ones close
+    if depth < level {
+      contents |= text;
+      opens.push(Open { start: index, end: index + c.len_utf8(), text, contents });
+      text = false;
+      level = depth;
+
+      if level == 0 {
+        opens.reverse();
+        return Some((end, opens));
+      }
+    }
+  }
+
+  None
+}
+
+// Returns whether josa can be selected by anything in a string,
+// other than the characters it looks past and parentheses
+pub(crate) fn has_text(noun: &str) -> bool {
+  noun
+    .chars()
+    .rev()
+    .any(|c| class(c).is_none() && !matches!(width::fold(c), '(' | ')'))
+}
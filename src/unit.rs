@@ -0,0 +1,102 @@
+use std::convert::TryFrom;
+
+use crate::{number, JongseongKind, Result};
+
+// Units of measurement and the jongseong of their Korean reading.
+// Units of two or more letters are matched case-insensitively, e.g. KG and kg.
+static UNITS: [(&str, JongseongKind); 56] = [
+  ("%", JongseongKind::Open),      // 퍼센트
+  ("‰", JongseongKind::Closed),    // 퍼밀
+  ("°", JongseongKind::Open),      // 도
+  ("°C", JongseongKind::Open),     // 도
+  ("°F", JongseongKind::Open),     // 도
+  ("℃", JongseongKind::Open),      // 도
+  ("℉", JongseongKind::Open),      // 도
+  ("㎏", JongseongKind::Closed),   // 킬로그램
+  ("㎎", JongseongKind::Closed),   // 밀리그램
+  ("㎝", JongseongKind::Open),     // 센티미터
+  ("㎜", JongseongKind::Open),     // 밀리미터
+  ("㎞", JongseongKind::Open),     // 킬로미터
+  ("㎖", JongseongKind::Open),     // 밀리리터
+  ("ℓ", JongseongKind::Open),      // 리터
+  ("㎡", JongseongKind::Open),     // 제곱미터
+  ("g", JongseongKind::Closed),    // 그램
+  ("kg", JongseongKind::Closed),   // 킬로그램
+  ("mg", JongseongKind::Closed),   // 밀리그램
+  ("t", JongseongKind::Closed),    // 톤
+  ("lb", JongseongKind::Open),     // 파운드
+  ("oz", JongseongKind::Open),     // 온스
+  ("m", JongseongKind::Open),      // 미터
+  ("km", JongseongKind::Open),     // 킬로미터
+  ("cm", JongseongKind::Open),     // 센티미터
+  ("mm", JongseongKind::Open),     // 밀리미터
+  ("nm", JongseongKind::Open),     // 나노미터
+  ("in", JongseongKind::Open),     // 인치
+  ("ft", JongseongKind::Open),     // 피트
+  ("l", JongseongKind::Open),      // 리터
+  ("L", JongseongKind::Open),      // 리터
+  ("ml", JongseongKind::Open),     // 밀리리터
+  ("km/h", JongseongKind::Open),   // 킬로미터
+  ("m/s", JongseongKind::Open),    // 미터 매 초
+  ("h", JongseongKind::Closed),    // 시간
+  ("min", JongseongKind::Closed),  // 분
+  ("s", JongseongKind::Open),      // 초
+  ("ms", JongseongKind::Open),     // 밀리초
+  ("kb", JongseongKind::Open),     // 킬로바이트
+  ("mb", JongseongKind::Open),     // 메가바이트
+  ("gb", JongseongKind::Open),     // 기가바이트
+  ("tb", JongseongKind::Open),     // 테라바이트
+  ("hz", JongseongKind::Open),     // 헤르츠
+  ("khz", JongseongKind::Open),    // 킬로헤르츠
+  ("mhz", JongseongKind::Open),    // 메가헤르츠
+  ("ghz", JongseongKind::Open),    // 기가헤르츠
+  ("w", JongseongKind::Open),      // 와트
+  ("kw", JongseongKind::Open),     // 킬로와트
+  ("kwh", JongseongKind::Open),    // 킬로와트시
+  ("v", JongseongKind::Open),      // 볼트
+  ("mah", JongseongKind::Open),    // 밀리암페어시
+  ("cal", JongseongKind::Open),    // 칼로리
+  ("kcal", JongseongKind::Open),   // 킬로칼로리
+  ("px", JongseongKind::Closed),   // 픽셀
+  ("pt", JongseongKind::Open),     // 포인트
+  ("dpi", JongseongKind::Open),    // 디피아이
+  ("fps", JongseongKind::Open)     // 에프피에스
+];
+
+// Whether a string ends with a unit right after a number, optionally separated by a space.
+// A unit of a single letter is matched case-sensitively, so that 5G is not read as 5 그램.
+pub(crate) fn follows_number(noun: &str, unit: &str) -> bool {
+  if unit.is_empty() || noun.len() < unit.len() || !noun.is_char_boundary(noun.len() - unit.len()) {
+    return false;
+  }
+
+  let (number, suffix) = noun.split_at(noun.len() - unit.len());
+  let same = match unit.chars().count() {
+    1 => suffix == unit,
+    _ => suffix.eq_ignore_ascii_case(unit)
+  };
+
+  same && number::classify(number.strip_suffix(' ').unwrap_or(number)).is_some()
+}
+
+// Classifies a string ending in a number and a unit by the Korean reading of the unit,
+// e.g. 3kg as 삼 킬로그램 and 50% as 오십 퍼센트.
+// Custom units, given with their Korean reading, take precedence over the builtin ones,
+// except those with an empty reading.
+// Returns `None` if the string does not end with a known unit after a number.
+pub(crate) fn classify(noun: &str, custom: &[(String, String)]) -> Option<Result<JongseongKind>> {
+  let custom = custom
+    .iter()
+    .filter(|(unit, reading)| !reading.is_empty() && follows_number(noun, unit))
+    .max_by_key(|(unit, _)| unit.len());
+
+  if let Some((_, reading)) = custom {
+    return Some(JongseongKind::try_from(reading.chars().last()?));
+  }
+
+  UNITS
+    .iter()
+    .filter(|&&(unit, _)| follows_number(noun, unit))
+    .max_by_key(|&&(unit, _)| unit.len())
+    .map(|&(_, kind)| Ok(kind))
+}
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+// Folds a fullwidth form from U+FF01 (！) to U+FF5E (～) into its ASCII character,
+// and leaves other characters as is
+pub(crate) fn fold(c: char) -> char {
+  match c {
+    '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+    _ => c
+  }
+}
+
+// Folds the fullwidth forms in the last word of a string into their ASCII characters
+pub(crate) fn fold_str(noun: &str) -> Cow<'_, str> {
+  fold_last_word(noun, fold)
+}
+
+// Folds the characters of the last word of a string, which is all that josa selection reads,
+// so that the rest of a long string is not copied on every selection
+pub(crate) fn fold_last_word(noun: &str, fold: fn(char) -> char) -> Cow<'_, str> {
+  let start = noun
+    .char_indices()
+    .rev()
+    .find(|&(_, c)| c.is_whitespace())
+    .map_or(0, |(i, c)| i + c.len_utf8());
+  let (rest, word) = noun.split_at(start);
+
+  if word.chars().any(|c| fold(c) != c) {
+    Cow::Owned(rest.chars().chain(word.chars().map(fold)).collect())
+  } else {
+    Cow::Borrowed(noun)
+  }
+}
@@ -0,0 +1,48 @@
+use crate::{jamo, JongseongKind};
+
+// Conjoining jungseong, including the archaic ones in Hangul Jamo Extended-B such as ᆞ (아래아)
+fn is_jungseong(c: char) -> bool {
+  matches!(c, '\u{1160}'..='\u{11a7}' | '\u{d7b0}'..='\u{d7c6}')
+}
+
+// Conjoining jongseong, including the archaic ones in Hangul Jamo Extended-B
+fn is_jongseong(c: char) -> bool {
+  matches!(c, '\u{11a8}'..='\u{11ff}' | '\u{d7cb}'..='\u{d7fb}')
+}
+
+// Classifies a jongseong, where archaic clusters starting with ㄹ such as ᇙ (ㄹㆆ) are treated as ㄹ,
+// except those followed by ㄱ, ㅁ or a stop of ㅂ such as ᇌ (ㄹㄱㅅ) and ᇑ (ㄹㅁㄱ),
+// which are closed like the modern ㄺ and ㄻ
+fn jongseong(c: char) -> JongseongKind {
+  match c {
+    '\u{11cc}'
+    | '\u{11d1}'..='\u{11d4}'
+    | '\u{11d8}'
+    | '\u{d7d5}'
+    | '\u{d7d6}'
+    | '\u{d7d8}'..='\u{d7da}'
+    | '\u{d7dd}' => JongseongKind::Closed,
+    '\u{11cd}'..='\u{11d0}' | '\u{11d5}'..='\u{11d7}' | '\u{11d9}' | '\u{d7db}' | '\u{d7dc}' => {
+      JongseongKind::Rieul
+    }
+    _ => jamo::conjoining(c).unwrap_or(JongseongKind::Closed)
+  }
+}
+
+// Classifies an archaic Hangul syllable written in conjoining jamo at the end of a string, e.g. ᄒᆞᆫ as closed.
+// A run of jongseong such as ᆯᆨ spells a cluster, which is classified by its last consonant.
+// Like modern conjoining jamo, a lone jungseong or jongseong is read as the ending itself.
+// Returns `None` if the string does not end with a conjoining jungseong or jongseong.
+pub(crate) fn classify(noun: &str) -> Option<JongseongKind> {
+  let last = noun.chars().last()?;
+
+  if is_jungseong(last) {
+    return Some(JongseongKind::Open);
+  }
+
+  if !is_jongseong(last) {
+    return None;
+  }
+
+  Some(jongseong(last))
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+	use josa::copula::{conjugate, CopulaEnding};
+
+	fn expected(ending: CopulaEnding) -> (&'static str, &'static str) {
+		match ending {
+			CopulaEnding::Ida => ("학생이다", "의사다"),
+			CopulaEnding::Imnida => ("학생입니다", "의사입니다"),
+			CopulaEnding::Imnikka => ("학생입니까", "의사입니까"),
+			CopulaEnding::Ieyo => ("학생이에요", "의사예요"),
+			CopulaEnding::IeyoQuestion => ("학생이에요?", "의사예요?"),
+			CopulaEnding::Ieotda => ("학생이었다", "의사였다"),
+			CopulaEnding::Ieotseumnida => ("학생이었습니다", "의사였습니다"),
+			CopulaEnding::Ieotseumnikka => ("학생이었습니까", "의사였습니까"),
+			CopulaEnding::Ieosseoyo => ("학생이었어요", "의사였어요"),
+			CopulaEnding::Ieotgo => ("학생이었고", "의사였고"),
+			CopulaEnding::Igoyo => ("학생이고요", "의사고요"),
+			CopulaEnding::Igo => ("학생이고", "의사고"),
+			CopulaEnding::Ijiyo => ("학생이지요", "의사지요"),
+			CopulaEnding::Inji => ("학생인지", "의사인지"),
+			CopulaEnding::Inde => ("학생인데", "의사인데")
+		}
+	}
+
+	#[test]
+	fn conjugate_every_ending() {
+		let endings = [
+			CopulaEnding::Ida,
+			CopulaEnding::Imnida,
+			CopulaEnding::Imnikka,
+			CopulaEnding::Ieyo,
+			CopulaEnding::IeyoQuestion,
+			CopulaEnding::Ieotda,
+			CopulaEnding::Ieotseumnida,
+			CopulaEnding::Ieotseumnikka,
+			CopulaEnding::Ieosseoyo,
+			CopulaEnding::Ieotgo,
+			CopulaEnding::Igoyo,
+			CopulaEnding::Igo,
+			CopulaEnding::Ijiyo,
+			CopulaEnding::Inji,
+			CopulaEnding::Inde,
+		];
+
+		for &ending in endings.iter() {
+			let (closed, open) = expected(ending);
+
+			assert_eq!(conjugate("학생", ending).unwrap(), closed);
+			assert_eq!(conjugate("의사", ending).unwrap(), open);
+		}
+
+		assert_eq!(conjugate("서울", CopulaEnding::Ieotda).unwrap(), "서울이었다");
+	}
+
+	#[test]
+	fn conjugate_trailing_characters() {
+		assert_eq!(conjugate("의사.", CopulaEnding::Ida).unwrap(), "의사다.");
+		assert_eq!(conjugate("의사 ", CopulaEnding::Ida).unwrap(), "의사다 ");
+		assert_eq!(conjugate("학생!", CopulaEnding::Ieyo).unwrap(), "학생이에요!");
+		assert_eq!(conjugate("의사 .", CopulaEnding::Ieotda).unwrap(), "의사였다 .");
+		assert_eq!(conjugate("《홍길동전》", CopulaEnding::Ida).unwrap(), "《홍길동전》이다");
+		assert_eq!(conjugate("《의사!》", CopulaEnding::Ida).unwrap(), "《의사!》다");
+		assert_eq!(conjugate("《의사!》.", CopulaEnding::Ida).unwrap(), "《의사!》다.");
+		assert_eq!(conjugate("갤럭시™", CopulaEnding::Imnida).unwrap(), "갤럭시™입니다");
+	}
+
+	#[test]
+	fn conjugate_errors() {
+		assert!(conjugate("", CopulaEnding::Ida).is_err());
+		#[cfg(not(feature = "loanwords"))]
+		assert!(conjugate("doctor", CopulaEnding::Ida).is_err());
+		assert!(conjugate("josa", CopulaEnding::Ida).is_err());
+	}
+}
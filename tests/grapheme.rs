@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "grapheme")]
+	#[test]
+	fn grapheme_aware() {
+		use josa::{Selector, Placement, EuRo, EunNeun, IGa};
+
+		let scalar = Selector::new();
+		let grapheme = Selector::new().grapheme_aware(true);
+
+		// 각 followed by a combining acute accent
+		assert!(scalar.select("각\u{301}", IGa).is_err());
+		assert_eq!(grapheme.select("각\u{301}", IGa).unwrap(), "이");
+		assert_eq!(grapheme.select("고양이\u{301}", IGa).unwrap(), "가");
+		assert!(scalar.select("가\u{20e3}", IGa).is_err());
+		assert_eq!(grapheme.select("가\u{20e3}", IGa).unwrap(), "가");
+
+		// A flag of two regional indicators is skipped as a whole either way
+		assert_eq!(scalar.select("대한민국🇰🇷", IGa).unwrap(), "이");
+		assert_eq!(grapheme.select("대한민국🇰🇷", IGa).unwrap(), "이");
+		assert!(scalar.select("🇰🇷", IGa).is_err());
+		assert!(grapheme.select("🇰🇷", IGa).is_err());
+
+		let mut before = "대한민국🇰🇷".to_owned();
+		grapheme.clone().emoji(Placement::Before).push_josa(&mut before, EunNeun);
+
+		assert_eq!(before, "대한민국은🇰🇷");
+
+		// Decomposed Hangul is composed as usual
+		assert_eq!(grapheme.select("\u{1100}\u{1161}\u{11a8}", IGa).unwrap(), "이");
+		assert_eq!(grapheme.select("\u{1100}\u{1161}\u{301}", IGa).unwrap(), "가");
+
+		// Latin letters and keycaps are read by their base letter and digit
+		let latin = Selector::new().latin_heuristic(true).grapheme_aware(true);
+
+		assert_eq!(latin.select("cafe\u{301}", EuRo).unwrap(), "로");
+		assert_eq!(grapheme.select("1\u{fe0f}\u{20e3}", EuRo).unwrap(), "로");
+
+		let mut accent = "각\u{301}".to_owned();
+		let mut fallback = "각\u{301}".to_owned();
+
+		grapheme.push_josa(&mut accent, EunNeun);
+		scalar.push_josa(&mut fallback, EunNeun);
+
+		assert_eq!(accent, "각\u{301}은");
+		assert_eq!(fallback, "각\u{301}은(는)");
+	}
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn greek_letters() {
+		use josa::{Selector, EuRo, EunNeun, IGa};
+
+		let selector = Selector::new().greek_letters(true);
+		let letters = [
+			("α", "가", "로"),
+			("계수 β", "가", "로"),
+			("Γ", "가", "로"),
+			("λ", "가", "로"),
+			("ε", "이", "으로"),
+			("Ο", "이", "으로"),
+			("υ", "이", "으로"),
+			("Σ", "가", "로"),
+			("ς", "가", "로"),
+			("ω", "가", "로"),
+		];
+
+		for &(noun, i_ga, eu_ro) in letters.iter() {
+			assert_eq!(selector.select(noun, IGa).unwrap(), i_ga, "{}", noun);
+			assert_eq!(selector.select(noun, EuRo).unwrap(), eu_ro, "{}", noun);
+		}
+
+		let mut beta = "β".to_owned();
+		selector.push_josa(&mut beta, EunNeun);
+
+		assert_eq!(beta, "β는");
+		assert!(Selector::new().select("β", IGa).is_err());
+		assert!(selector.select("ϐ", IGa).is_err());
+	}
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "hanja")]
+	#[test]
+	fn hanja() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("株式會社", IGa).unwrap(), "가");
+		assert_eq!(select("大韓民國", EunNeun).unwrap(), "은");
+		assert_eq!(select("金", IGa).unwrap(), "이");
+		assert_eq!(select("李", IGa).unwrap(), "가");
+		assert_eq!(select("首爾", EuRo).unwrap(), "로");
+		assert_eq!(select("北", EuRo).unwrap(), "으로");
+		assert_eq!(select("說", EuRo).unwrap(), "로");
+		assert_eq!(select("韓國語", IGa).unwrap(), "가");
+
+		let mut company = "三星電子".to_owned();
+		company.push_josa(EunNeun);
+
+		assert_eq!(company, "三星電子는");
+		assert!(select("𠀀", IGa).is_err());
+	}
+
+	#[cfg(not(feature = "hanja"))]
+	#[test]
+	fn hanja() {
+		use josa::{select, IGa};
+
+		assert!(select("株式會社", IGa).is_err());
+	}
+}
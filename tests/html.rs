@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn select_html() {
+		use josa::{select_html, Error, Eu, EunNeun, IGa};
+
+		assert_eq!(select_html("<b>손</b>", Eu).unwrap(), "으");
+		assert_eq!(select_html("<b>칼</b>", Eu).unwrap(), "");
+		assert_eq!(select_html(r#"<span class="bold">고양이</span>"#, IGa).unwrap(), "가");
+		assert_eq!(select_html("고양이&nbsp;", IGa).unwrap(), "가");
+		assert_eq!(select_html("<p><em>사람</em></p>", EunNeun).unwrap(), "은");
+		assert_eq!(select_html("사람<!-- 주석 --><br/>", EunNeun).unwrap(), "은");
+		assert_eq!(select_html("&#44608;", IGa).unwrap(), "이");
+		assert_eq!(select_html("&#xAC00;", IGa).unwrap(), "가");
+		assert_eq!(select_html("톰 &amp; 제리", IGa).unwrap(), "가");
+		assert_eq!(select_html("톰 & 제리", IGa).unwrap(), "가");
+		assert_eq!(select_html("a < b 고양이", IGa).unwrap(), "가");
+		assert_eq!(select_html("<b>3 <</b> 사람", EunNeun).unwrap(), "은");
+
+		assert!(matches!(select_html("<b>고양이</b", IGa), Err(Error::MalformedHtml)));
+		assert!(matches!(select_html("고양이<!-- 주석", IGa), Err(Error::MalformedHtml)));
+		assert!(matches!(select_html("&#xD800;", IGa), Err(Error::MalformedHtml)));
+		assert!(matches!(select_html("<b></b>", IGa), Err(Error::EmptyStr)));
+		assert!(matches!(select_html("고양이&unknown;", IGa), Err(Error::ParseSyllable(';'))));
+		assert!(matches!(select_html("화살표 <-", IGa), Err(Error::ParseSyllable('-'))));
+	}
+}
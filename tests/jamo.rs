@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn decomposed() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		let cat = "\u{1100}\u{1169}\u{110b}\u{1163}\u{110b}\u{1175}";
+		let book = "\u{110e}\u{1162}\u{11a8}";
+		let seoul = "\u{1109}\u{1165}\u{110b}\u{116e}\u{11af}";
+
+		assert_eq!(select(cat, IGa).unwrap(), "가");
+		assert_eq!(select(book, IGa).unwrap(), "이");
+		assert_eq!(select(book, EuRo).unwrap(), "으로");
+		assert_eq!(select(seoul, EuRo).unwrap(), "로");
+		assert_eq!(select("사\u{11ab}", EunNeun).unwrap(), "은");
+		assert_eq!(select("\u{1100}\u{1169}.", EunNeun).unwrap(), "는");
+
+		let mut noun = book.to_owned();
+		noun.push_josa(EunNeun);
+
+		assert_eq!(noun, "\u{110e}\u{1162}\u{11a8}은");
+		assert!(select("\u{1100}", IGa).is_err());
+	}
+
+	#[test]
+	fn compatibility_jamo() {
+		use josa::{select, EuRo, IGa};
+
+		let letters = [
+			("ㄱ", "이", "으로"),
+			("ㄴ", "이", "으로"),
+			("ㄹ", "이", "로"),
+			("ㅇ", "이", "으로"),
+			("ㅎ", "이", "으로"),
+			("ㄲ", "이", "으로"),
+			("ㄺ", "이", "으로"),
+			("ㅀ", "이", "으로"),
+			("ㆆ", "이", "으로"),
+			("ㅏ", "가", "로"),
+			("ㅢ", "가", "로"),
+			("ㆍ", "가", "로"),
+			("첫 글자는 ㄱ", "이", "으로"),
+		];
+
+		for &(noun, i_ga, eu_ro) in letters.iter() {
+			assert_eq!(select(noun, IGa).unwrap(), i_ga, "{}", noun);
+			assert_eq!(select(noun, EuRo).unwrap(), eu_ro, "{}", noun);
+		}
+
+		assert!(select("\u{3164}", IGa).is_err());
+	}
+
+	#[test]
+	fn jamo_runs() {
+		use josa::{select, EunNeun, IGa, JamoRun, JosaExt, Selector};
+
+		assert_eq!(select("고마워ㅠㅠ", EunNeun).unwrap(), "는");
+		assert_eq!(select("재밌닼ㅋㅋ", IGa).unwrap(), "이");
+		assert_eq!(select("감사합니다ㅎㅎ", IGa).unwrap(), "가");
+		assert_eq!(select("진짜ㅋ", IGa).unwrap(), "가");
+		assert_eq!(select("진짜 ㅋㅋ.", IGa).unwrap(), "가");
+		assert_eq!(select("ㅋㅋ", EunNeun).unwrap(), "은");
+		assert_eq!(select("ㄱ", IGa).unwrap(), "이");
+
+		let pronounce = Selector::new().jamo_runs(JamoRun::Pronounce);
+
+		assert_eq!(pronounce.select("고마워ㅠㅠ", EunNeun).unwrap(), "는");
+		assert_eq!(pronounce.select("재밌닼ㅋㅋ", IGa).unwrap(), "가");
+		assert_eq!(pronounce.select("알겠어ㅇㅇ", IGa).unwrap(), "이");
+		assert_eq!(pronounce.select("ㅋㅋ", EunNeun).unwrap(), "는");
+		assert_eq!(pronounce.select("ㄱ", IGa).unwrap(), "이");
+
+		let mut thanks = "고마워ㅠㅠ".to_owned();
+		let mut laugh = "ㅋㅋㅋ".to_owned();
+
+		thanks.push_josa(EunNeun);
+		laugh.push_josa(EunNeun);
+
+		assert_eq!(thanks, "고마워ㅠㅠ는");
+		assert_eq!(laugh, "ㅋㅋㅋ은");
+	}
+
+	#[test]
+	fn halfwidth_jamo() {
+		use josa::{select, EuRo, IGa, JosaExt};
+
+		let ranges = [
+			(0xffa1, 0xffbe, 0x3131),
+			(0xffc2, 0xffc7, 0x314f),
+			(0xffca, 0xffcf, 0x3155),
+			(0xffd2, 0xffd7, 0x315b),
+			(0xffda, 0xffdc, 0x3161),
+		];
+
+		for &(first, last, compatibility) in ranges.iter() {
+			for code in first..=last {
+				let halfwidth = char::from_u32(code).unwrap().to_string();
+				let jamo = char::from_u32(compatibility + code - first).unwrap().to_string();
+
+				assert_eq!(select(&halfwidth, IGa).unwrap(), select(&jamo, IGa).unwrap(), "{}", halfwidth);
+				assert_eq!(select(&halfwidth, EuRo).unwrap(), select(&jamo, EuRo).unwrap(), "{}", halfwidth);
+			}
+		}
+
+		assert_eq!(select("\u{ffa1}", IGa).unwrap(), "이");
+		assert_eq!(select("\u{ffa9}", EuRo).unwrap(), "로");
+		assert_eq!(select("\u{ffc2}", IGa).unwrap(), "가");
+
+		for &code in [0xffa0, 0xffc0, 0xffc1, 0xffc8, 0xffc9, 0xffd0, 0xffd1, 0xffd8, 0xffd9].iter() {
+			let mut noun = char::from_u32(code).unwrap().to_string();
+
+			assert!(select(&noun, IGa).is_err());
+
+			noun.push_josa(IGa);
+			assert!(noun.ends_with("이(가)"));
+		}
+	}
+
+	#[test]
+	fn conjoining_jamo() {
+		use josa::{select, EuRo, IGa, JosaExt};
+
+		assert_eq!(select("\u{11a8}", IGa).unwrap(), "이");
+		assert_eq!(select("\u{11a8}", EuRo).unwrap(), "으로");
+		assert_eq!(select("\u{11af}", EuRo).unwrap(), "로");
+		assert_eq!(select("\u{11b2}", EuRo).unwrap(), "로");
+		assert_eq!(select("\u{11b0}", EuRo).unwrap(), "으로");
+		assert_eq!(select("\u{11c2}", IGa).unwrap(), "이");
+		assert_eq!(select("책\u{11a8}", IGa).unwrap(), "이");
+		assert_eq!(select("\u{1161}", IGa).unwrap(), "가");
+		assert_eq!(select("\u{1175}", EuRo).unwrap(), "로");
+		assert_eq!(select("a\u{1161}", IGa).unwrap(), "가");
+
+		let mut noun = "\u{11ab}".to_owned();
+		noun.push_josa(IGa);
+
+		assert_eq!(noun, "\u{11ab}이");
+	}
+
+	#[test]
+	fn enclosed() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("코리아㈜", EunNeun).unwrap(), "는");
+		assert_eq!(select("㈀", IGa).unwrap(), "이");
+		assert_eq!(select("㈃", EuRo).unwrap(), "로");
+		assert_eq!(select("㈎", IGa).unwrap(), "가");
+		assert_eq!(select("㈛", IGa).unwrap(), "가");
+		assert_eq!(select("㈝", IGa).unwrap(), "이");
+		assert_eq!(select("㈞", IGa).unwrap(), "가");
+		assert_eq!(select("㉠", IGa).unwrap(), "이");
+		assert_eq!(select("㉧", IGa).unwrap(), "이");
+		assert_eq!(select("㉭", IGa).unwrap(), "이");
+		assert_eq!(select("㉮", IGa).unwrap(), "가");
+		assert_eq!(select("㉻", IGa).unwrap(), "가");
+		assert_eq!(select("㉼", IGa).unwrap(), "가");
+		assert_eq!(select("㉾", IGa).unwrap(), "가");
+
+		let mut company = "코리아㈜".to_owned();
+		company.push_josa(EunNeun);
+
+		assert_eq!(company, "코리아㈜는");
+		assert!(select("㉿", IGa).is_err());
+	}
+}
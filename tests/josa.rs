@@ -15,4 +15,427 @@ mod tests {
 
 		assert_eq!(sentence, "유진은 고등어가 먹고싶다");
 	}
+
+	#[test]
+	fn josas() {
+		use josa::Josa::{
+			AYa, EuRo, EuRoSeo, EuRoSsi, EuRoButeo, Ida, Ina, Iran, Ideunga, Inama, Iyamallo,
+			Irang, Iyeo, Imyeo, Ieyo, Ieotda, Irago, Iraneun, Irado, Iramyeon, Ideunji, Inikka,
+			Ijiman, Iraseo, Ija, Isiyeo, EunKeonyeong, EuRoUi, GwaUi, GwaNeun, GwaDo, Igo, Igeona,
+			Idoe, Inya, Imyeon, Ini, Ieoseo, Ideonga, Ine, Iradeunji, Iranda, Irani, Iramyeonseo,
+			Iragon, Iroda, Iolsida, Irandeul, Irageona, Iragiboda, Iya, Iraya, Igie
+		};
+		use josa::{select, JosaExt};
+
+		let josas = [
+			(AYa, "철수", "야"),
+			(AYa, "하늘", "아"),
+			(AYa, "멍멍이", "야"),
+			(AYa, "유진", "아"),
+			(EuRo, "손", "으로"),
+			(EuRo, "칼", "로"),
+			(EuRo, "바다", "로"),
+			(EuRoSeo, "팀장", "으로서"),
+			(EuRoSeo, "리더", "로서"),
+			(EuRoSeo, "딸", "로서"),
+			(EuRoSsi, "손", "으로써"),
+			(EuRoSsi, "칼", "로써"),
+			(EuRoSsi, "바다", "로써"),
+			(EuRoButeo, "친구", "로부터"),
+			(EuRoButeo, "선생님", "으로부터"),
+			(EuRoButeo, "서울", "로부터"),
+			(Ida, "고양이", "다"),
+			(Ida, "책", "이다"),
+			(Ida, "하늘", "이다"),
+			(Ida, "작가", "다"),
+			(Ina, "커피", "나"),
+			(Ina, "빵", "이나"),
+			(Ina, "물", "이나"),
+			(Iran, "사랑", "이란"),
+			(Iran, "자유", "란"),
+			(Iran, "인생", "이란"),
+			(Ideunga, "버스", "든가"),
+			(Ideunga, "지하철", "이든가"),
+			(Ideunga, "택시", "든가"),
+			(Inama, "조금", "이나마"),
+			(Inama, "위로", "나마"),
+			(Inama, "물", "이나마"),
+			(Iyamallo, "너", "야말로"),
+			(Iyamallo, "책", "이야말로"),
+			(Iyamallo, "하늘", "이야말로"),
+			(Irang, "친구", "랑"),
+			(Irang, "동생", "이랑"),
+			(Irang, "겨울", "이랑"),
+			(Iyeo, "님", "이여"),
+			(Iyeo, "바다", "여"),
+			(Iyeo, "하늘", "이여"),
+			(Imyeo, "학생", "이며"),
+			(Imyeo, "운동선수", "며"),
+			(Imyeo, "서울", "이며"),
+			(Ieyo, "고양이", "예요"),
+			(Ieyo, "책", "이에요"),
+			(Ieyo, "연필", "이에요"),
+			(Ieotda, "집사", "였다"),
+			(Ieotda, "학생", "이었다"),
+			(Ieotda, "하늘", "이었다"),
+			(Irago, "천재", "라고"),
+			(Irago, "장인", "이라고"),
+			(Irago, "달인", "이라고"),
+			(Iraneun, "유진", "이라는"),
+			(Iraneun, "나비", "라는"),
+			(Iraneun, "마을", "이라는"),
+			(Irado, "물", "이라도"),
+			(Irado, "커피", "라도"),
+			(Irado, "밥", "이라도"),
+			(Iramyeon, "너", "라면"),
+			(Iramyeon, "사장님", "이라면"),
+			(Iramyeon, "서울", "이라면"),
+			(Ideunji, "무엇", "이든지"),
+			(Ideunji, "어디", "든지"),
+			(Ideunji, "물", "이든지"),
+			(Inikka, "학생", "이니까"),
+			(Inikka, "프로", "니까"),
+			(Inikka, "주말", "이니까"),
+			(Ijiman, "초보", "지만"),
+			(Ijiman, "학생", "이지만"),
+			(Ijiman, "전문가", "지만"),
+			(Iraseo, "주말", "이라서"),
+			(Iraseo, "휴가", "라서"),
+			(Iraseo, "겨울", "이라서"),
+			(Ija, "작가", "이자"),
+			(Ija, "선생님", "이자"),
+			(Ija, "회장", "이자"),
+			(Isiyeo, "신", "이시여"),
+			(Isiyeo, "여왕", "이시여"),
+			(Isiyeo, "폐하", "시여"),
+			(EunKeonyeong, "밥", "은커녕"),
+			(EunKeonyeong, "사과", "는커녕"),
+			(EunKeonyeong, "물", "은커녕"),
+			(EuRoUi, "미래", "로의"),
+			(EuRoUi, "정상", "으로의"),
+			(EuRoUi, "서울", "로의"),
+			(GwaUi, "중국", "과의"),
+			(GwaUi, "미국", "과의"),
+			(GwaUi, "러시아", "와의"),
+			(GwaUi, "이스라엘", "과의"),
+			(GwaNeun, "그것", "과는"),
+			(GwaNeun, "너", "와는"),
+			(GwaNeun, "서울", "과는"),
+			(GwaDo, "돈", "과도"),
+			(GwaDo, "친구", "와도"),
+			(GwaDo, "하늘", "과도"),
+			(Igo, "의사", "고"),
+			(Igo, "작가", "고"),
+			(Igo, "서울", "이고"),
+			(Igeona, "현금", "이거나"),
+			(Igeona, "카드", "거나"),
+			(Igeona, "달걀", "이거나"),
+			(Idoe, "원칙", "이되"),
+			(Idoe, "자유", "되"),
+			(Idoe, "계약기간", "이되"),
+			(Inya, "밥", "이냐"),
+			(Inya, "너", "냐"),
+			(Inya, "빵", "이냐"),
+			(Inya, "물", "이냐"),
+			(Imyeon, "학생", "이면"),
+			(Imyeon, "회원", "이면"),
+			(Imyeon, "무료", "면"),
+			(Ini, "마감", "이니"),
+			(Ini, "무료", "니"),
+			(Ini, "연말", "이니"),
+			(Ieoseo, "휴일", "이어서"),
+			(Ieoseo, "휴가", "여서"),
+			(Ieoseo, "겨울", "이어서"),
+			(Ideonga, "철수", "던가"),
+			(Ideonga, "영수", "던가"),
+			(Ideonga, "선생님", "이던가"),
+			(Ine, "고양이", "네"),
+			(Ine, "강아지", "네"),
+			(Ine, "사람", "이네"),
+			(Iradeunji, "과일", "이라든지"),
+			(Iradeunji, "채소", "라든지"),
+			(Iradeunji, "고기", "라든지"),
+			(Iranda, "토끼", "란다"),
+			(Iranda, "호랑이", "란다"),
+			(Iranda, "곰", "이란다"),
+			(Irani, "금요일", "이라니"),
+			(Irani, "우승", "이라니"),
+			(Irani, "공짜", "라니"),
+			(Iramyeonseo, "천재", "라면서"),
+			(Iramyeonseo, "전문가", "라면서"),
+			(Iramyeonseo, "학생", "이라면서"),
+			(Iragon, "돈", "이라곤"),
+			(Iragon, "재주", "라곤"),
+			(Iragon, "물", "이라곤"),
+			(Iroda, "천운", "이로다"),
+			(Iroda, "경사", "로다"),
+			(Iroda, "하늘", "이로다"),
+			(Iolsida, "소인", "이올시다"),
+			(Iolsida, "나그네", "올시다"),
+			(Irandeul, "부모", "란들"),
+			(Irandeul, "신", "이란들"),
+			(Irandeul, "하늘", "이란들"),
+			(Irageona, "선물", "이라거나"),
+			(Irageona, "편지", "라거나"),
+			(Iragiboda, "친구", "라기보다"),
+			(Iragiboda, "취미", "라기보다"),
+			(Iragiboda, "습관", "이라기보다"),
+			(Iya, "너", "야"),
+			(Iya, "돈", "이야"),
+			(Iya, "물", "이야"),
+			(Iya, "하늘", "이야"),
+			(Iraya, "전문가", "라야"),
+			(Iraya, "회원", "이라야"),
+			(Igie, "학생", "이기에"),
+			(Igie, "프로", "기에"),
+			(Igie, "어른", "이기에"),
+			(Igie, "가을", "이기에"),
+		];
+
+		for &(josa, noun, expected) in josas.iter() {
+			assert_eq!(select(noun, josa).unwrap(), expected, "{}", noun);
+
+			let mut pushed = noun.to_owned();
+			pushed.push_josa(josa);
+
+			assert_eq!(pushed, format!("{}{}", noun, expected));
+			assert_eq!(noun.to_owned() + josa, pushed);
+		}
+	}
+
+	#[test]
+	fn unknown_endings() {
+		use josa::Josa::{
+			EuRoButeo, Ida, Iran, Iyeo, Ieyo, Iraneun, Ideunji, Inikka, Isiyeo, EunKeonyeong,
+			GwaUi, GwaNeun, Idoe, Ideonga, Ine, Irani, Iroda, Iolsida, Irandeul, Ijiman
+		};
+		use josa::{select, JosaExt};
+
+		let nouns = [
+			(EuRoButeo, "xqzt", "(으)로부터"),
+			(Ida, "wqxz", "(이)다"),
+			(Iran, "vrbq", "(이)란"),
+			(Iyeo, "qwxz", "(이)여"),
+			(Ieyo, "kjhq", "(이)에요"),
+			(Iraneun, "Nabi", "(이)라는"),
+			(Ideunji, "zzyxq", "(이)든지"),
+			(Inikka, "yujin", "(이)니까"),
+			(Isiyeo, "Xkqz", "(이)시여"),
+			(EunKeonyeong, "prrzt", "은(는)커녕"),
+			(GwaUi, "Gvxq", "와(과)의"),
+			(GwaNeun, "qj", "와(과)는"),
+			(Idoe, "Mnbvq", "(이)되"),
+			(Ideonga, "Cheolsu", "(이)던가"),
+			(Ine, "grzk", "(이)네"),
+			(Irani, "Hjkxz", "(이)라니"),
+			(Iroda, "tkqz", "(이)로다"),
+			(Iolsida, "plqxr", "(이)올시다"),
+			(Irandeul, "dfgz", "(이)란들"),
+			(Ijiman, "", ""),
+		];
+
+		for &(josa, noun, expected) in nouns.iter() {
+			assert!(select(noun, josa).is_err(), "{}", noun);
+
+			let mut pushed = noun.to_owned();
+			pushed.push_josa(josa);
+
+			assert_eq!(pushed, format!("{}{}", noun, expected));
+			assert_eq!(noun.to_owned() + josa, pushed);
+		}
+	}
+
+	#[cfg(not(feature = "loanwords"))]
+	#[test]
+	fn loanwords_without_dictionary() {
+		use josa::Josa::{
+			AYa, EuRo, EuRoSeo, EuRoSsi, Ina, Ideunga, Inama, Iyamallo, Irang, Imyeo, Ieotda,
+			Irago, Irado, Iramyeon, Ijiman, Iraseo, EuRoUi, GwaDo, Igo, Igeona, Inya, Imyeon,
+			Ini, Ieoseo, Iradeunji, Iranda, Iramyeonseo, Iragon, Irageona, Iragiboda, Iya, Iraya,
+			Igie, EuRoButeo, Ida, Iran, Iyeo, Ieyo, Ideunji, Isiyeo, EunKeonyeong, GwaUi, Idoe,
+			Ine, Irani, Iroda, Iolsida, Irandeul
+		};
+		use josa::JosaExt;
+
+		let nouns = [
+			(AYa, "player", "아(야)"),
+			(EuRo, "pioneer", "(으)로"),
+			(EuRoSeo, "manager", "(으)로서"),
+			(EuRoSsi, "signature", "(으)로써"),
+			(Ina, "tea", "(이)나"),
+			(Ideunga, "bus", "(이)든가"),
+			(Inama, "help", "(이)나마"),
+			(Iyamallo, "book", "(이)야말로"),
+			(Irang, "friend", "(이)랑"),
+			(Imyeo, "athlete", "(이)며"),
+			(Ieotda, "butler", "(이)었다"),
+			(Irago, "genius", "(이)라고"),
+			(Irado, "Galaxy", "(이)라도"),
+			(Iramyeon, "boss", "(이)라면"),
+			(Ijiman, "beginner", "(이)지만"),
+			(Iraseo, "weekend", "(이)라서"),
+			(EuRoUi, "future", "(으)로의"),
+			(GwaDo, "money", "와(과)도"),
+			(Igo, "doctor", "(이)고"),
+			(Igeona, "cash", "(이)거나"),
+			(Inya, "answer", "(이)냐"),
+			(Imyeon, "VIP", "면"),
+			(Ini, "deadline", "(이)니"),
+			(Ieoseo, "holiday", "(이)어서"),
+			(Iradeunji, "fruit", "(이)라든지"),
+			(Iranda, "bunny", "(이)란다"),
+			(Iramyeonseo, "expert", "(이)라면서"),
+			(Iragon, "money", "(이)라곤"),
+			(Irageona, "gift", "(이)라거나"),
+			(Iragiboda, "hobby", "(이)라기보다"),
+			(Iya, "money", "(이)야"),
+			(Iraya, "member", "(이)라야"),
+			(Igie, "pro", "(이)기에"),
+			(EuRoButeo, "sender", "(으)로부터"),
+			(Ida, "cat", "(이)다"),
+			(Iran, "love", "(이)란"),
+			(Iyeo, "sea", "(이)여"),
+			(Ieyo, "cat", "(이)에요"),
+			(Ideunji, "anything", "(이)든지"),
+			(Isiyeo, "Zeus", "(이)시여"),
+			(EunKeonyeong, "sorry", "은(는)커녕"),
+			(GwaUi, "Chile", "와(과)의"),
+			(Idoe, "Licensee", "(이)되"),
+			(Ine, "cat", "(이)네"),
+			(Irani, "Friday", "(이)라니"),
+			(Iroda, "luck", "(이)로다"),
+			(Iolsida, "traveler", "(이)올시다"),
+			(Irandeul, "god", "(이)란들"),
+		];
+
+		for &(josa, noun, expected) in nouns.iter() {
+			let mut pushed = noun.to_owned();
+			pushed.push_josa(josa);
+
+			assert_eq!(pushed, format!("{}{}", noun, expected));
+			assert_eq!(noun.to_owned() + josa, pushed);
+		}
+	}
+
+	#[test]
+	fn invariant() {
+		use josa::Josa::{Do, Man, Kkaji, Buteo, Mada, E, Eseo, Ege, Hante, Cheoreom, Boda, Bakke, Ija};
+		use josa::{select, JosaExt};
+
+		let josas = [
+			(Do, "도"),
+			(Man, "만"),
+			(Kkaji, "까지"),
+			(Buteo, "부터"),
+			(Mada, "마다"),
+			(E, "에"),
+			(Eseo, "에서"),
+			(Ege, "에게"),
+			(Hante, "한테"),
+			(Cheoreom, "처럼"),
+			(Boda, "보다"),
+			(Bakke, "밖에"),
+			(Ija, "이자"),
+		];
+
+		for &(josa, expected) in josas.iter() {
+			assert_eq!(select("고양이", josa).unwrap(), expected);
+			assert_eq!(select("하늘", josa).unwrap(), expected);
+			assert_eq!(select("책", josa).unwrap(), expected);
+			assert_eq!(select("cat", josa).unwrap(), expected);
+			assert!(select("", josa).is_err());
+
+			let mut empty = "".to_owned();
+			empty.push_josa(josa);
+
+			assert_eq!(empty, "");
+		}
+
+		assert_eq!("curry".to_owned() + Do, "curry도");
+		assert_eq!(select("manager", Ija).unwrap(), "이자");
+		assert_eq!(select("CEO", Ija).unwrap(), "이자");
+	}
+
+	#[test]
+	fn subject() {
+		use josa::{select_subject, Josa, JosaExt};
+
+		assert_eq!(select_subject("할아버지", true).unwrap(), "께서");
+		assert_eq!(select_subject("할아버지", false).unwrap(), "가");
+		assert_eq!(select_subject("선생님", false).unwrap(), "이");
+		assert_eq!(select_subject("Elizabeth", true).unwrap(), "께서");
+		assert!(select_subject("Elizabeth", false).is_err());
+		assert!(select_subject("", true).is_err());
+
+		let mut queen = "Elizabeth".to_owned();
+		queen.push_josa(Josa::subject(false));
+
+		assert_eq!(queen, "Elizabeth이(가)");
+		assert_eq!("선생님".to_owned() + Josa::subject(true), "선생님께서");
+	}
+
+	#[test]
+	fn dative() {
+		use josa::{select_dative, Josa, JosaExt, Register};
+
+		assert_eq!(select_dative("부모님", true, Register::Formal).unwrap(), "께");
+		assert_eq!(select_dative("부모님", true, Register::Casual).unwrap(), "께");
+		assert_eq!(select_dative("친구", false, Register::Formal).unwrap(), "에게");
+		assert_eq!(select_dative("친구", false, Register::Casual).unwrap(), "한테");
+		assert_eq!(select_dative("Tom", false, Register::Casual).unwrap(), "한테");
+		assert!(select_dative("", true, Register::Formal).is_err());
+
+		let mut teacher = "선생님".to_owned();
+		teacher.push_josa(Josa::dative(true, Register::Formal));
+
+		assert_eq!(teacher, "선생님께");
+	}
+
+	#[test]
+	fn compound_jongseong() {
+		use josa::Josa::{EuRo, IGa};
+		use josa::select;
+
+		assert_eq!(select("여덟", EuRo).unwrap(), "로");
+		assert_eq!(select("돐", EuRo).unwrap(), "로");
+		assert_eq!(select("닭", EuRo).unwrap(), "으로");
+		assert_eq!(select("값", EuRo).unwrap(), "으로");
+
+		assert_eq!(select("여덟", IGa).unwrap(), "이");
+		assert_eq!(select("돐", IGa).unwrap(), "이");
+		assert_eq!(select("닭", IGa).unwrap(), "이");
+		assert_eq!(select("값", IGa).unwrap(), "이");
+	}
+
+	#[test]
+	fn composed() {
+		use josa::Josa::{EuRo, GwaWa, IGa, EunNeun};
+
+		assert_eq!(EuRo.then("는").select("손").unwrap(), "으로는");
+		assert_eq!(EuRo.then("는").select("칼").unwrap(), "로는");
+		assert_eq!(GwaWa.then("도").select("돈").unwrap(), "과도");
+		assert_eq!(GwaWa.then("도").select("친구").unwrap(), "와도");
+		assert!(IGa.then("요").select("").is_err());
+		assert!(IGa.then("요").select("xqzt").is_err());
+
+		let mut hand = "손".to_owned();
+		let mut empty = "".to_owned();
+
+		EuRo.then("는").push_to(&mut hand);
+		EunNeun.then("커녕").push_to(&mut empty);
+
+		assert_eq!(hand, "손으로는");
+		assert_eq!(empty, "");
+		#[cfg(not(feature = "loanwords"))]
+		assert_eq!("pioneer".to_owned() + EuRo.then("는"), "pioneer(으)로는");
+		assert_eq!("사과".to_owned() + EunNeun.then("커녕"), "사과는커녕");
+
+		let tail = "는".to_owned();
+		let mut rice = "밥".to_owned();
+
+		rice += EuRo.then(&tail);
+
+		assert_eq!(rice, "밥으로는");
+		assert_eq!(GwaWa.then(&tail).select("친구").unwrap(), "와는");
+	}
 }
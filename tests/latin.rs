@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn latin_heuristic() {
+		use josa::{Selector, EuRo, EunNeun, IGa};
+
+		let selector = Selector::new().latin_heuristic(true);
+		let words = [
+			("Google", "이", "로"),
+			("Coffee", "가", "로"),
+			("Excel", "이", "로"),
+			("game", "이", "으로"),
+			("iPhone", "이", "으로"),
+			("King", "이", "으로"),
+			("Rock", "이", "으로"),
+			("project", "가", "로"),
+			("test", "가", "로"),
+			("web", "이", "으로"),
+			("team", "이", "으로"),
+			("shop", "이", "으로"),
+			("chat", "이", "으로"),
+			("computer", "가", "로"),
+			("bus", "가", "로"),
+			("Level 3 boss", "가", "로"),
+		];
+
+		for &(word, i_ga, eu_ro) in words.iter() {
+			assert_eq!(selector.select(word, IGa).unwrap(), i_ga, "{}", word);
+			assert_eq!(selector.select(word, EuRo).unwrap(), eu_ro, "{}", word);
+		}
+
+		let mut coffee = "Coffee".to_owned();
+		selector.push_josa(&mut coffee, EunNeun);
+
+		assert_eq!(coffee, "Coffee는");
+		#[cfg(not(feature = "loanwords"))]
+		assert!(Selector::new().select("Google", IGa).is_err());
+		assert!(Selector::new().select("Nolan", IGa).is_err());
+		assert_eq!(selector.select("Google!", IGa).unwrap(), "이");
+	}
+
+	#[cfg(feature = "loanwords")]
+	#[test]
+	fn loanwords() {
+		use josa::{is_known_loanword, select, Selector, EunNeun, IGa};
+
+		assert_eq!(select("orange", IGa).unwrap(), "가");
+		assert_eq!(select("Internet", IGa).unwrap(), "이");
+		assert_eq!(select("GOOGLE", EunNeun).unwrap(), "은");
+		assert_eq!(select("나의 laptop", EunNeun).unwrap(), "은");
+		assert!(select("josa", IGa).is_err());
+		assert_eq!(Selector::new().latin_heuristic(true).select("josa", IGa).unwrap(), "가");
+
+		assert!(is_known_loanword("Orange"));
+		assert!(!is_known_loanword("josa"));
+		assert!(!is_known_loanword("오렌지"));
+	}
+
+	#[cfg(feature = "loanwords")]
+	#[test]
+	fn loanword_readings() {
+		use josa::{JosaExt, AYa, EuRoSeo, IGa, Ideunga, Igeona, Igo, Ini, Iradeunji, Irageona, Iraya, Iyamallo};
+		use josa::copula::{conjugate, CopulaEnding};
+
+		let push = |noun: &str, josa| {
+			let mut noun = noun.to_owned();
+			noun.push_josa(josa);
+			noun
+		};
+
+		assert_eq!(push("player", AYa), "player야");
+		assert_eq!(push("manager", EuRoSeo), "manager로서");
+		assert_eq!(push("bus", Ideunga), "bus든가");
+		assert_eq!(push("book", Iyamallo), "book이야말로");
+		assert_eq!(push("doctor", Igo), "doctor고");
+		assert_eq!(push("cash", Igeona), "cash거나");
+		assert_eq!(push("deadline", Ini), "deadline이니");
+		assert_eq!(push("fruit", Iradeunji), "fruit라든지");
+		assert_eq!(push("gift", Irageona), "gift라거나");
+		assert_eq!(push("member", Iraya), "member라야");
+		assert_eq!(push("cat", IGa), "cat이");
+		assert_eq!(push("love", IGa), "love가");
+		assert_eq!(push("Friday", IGa), "Friday가");
+		assert_eq!(push("god", IGa), "god이");
+		assert_eq!(conjugate("doctor", CopulaEnding::Ida).unwrap(), "doctor다");
+	}
+
+	#[cfg(feature = "loanwords")]
+	#[test]
+	fn loanword_dictionary_size() {
+		use josa::is_known_loanword;
+
+		let words = [
+			"abacus", "barista", "croissant", "dumbbell", "espresso", "fintech", "gelato", "hackathon",
+			"influencer", "jukebox", "kickboard", "latte", "metaverse", "netizen", "officetel",
+			"podcast", "quarterback", "refactoring", "smoothie", "tumbler", "ukulele", "vlog",
+			"webtoon", "xylophone", "yoyo", "zigzag"
+		];
+
+		for word in words.iter() {
+			assert!(is_known_loanword(word), "{}", word);
+		}
+	}
+
+	#[test]
+	fn acronyms() {
+		use josa::{select, EulReul, EuRo, IGa};
+
+		assert_eq!(select("API", IGa).unwrap(), "가");
+		assert_eq!(select("SQL", EulReul).unwrap(), "을");
+		assert_eq!(select("SQL", EuRo).unwrap(), "로");
+		assert_eq!(select("URL", EuRo).unwrap(), "로");
+		assert_eq!(select("HTML", IGa).unwrap(), "이");
+		assert_eq!(select("JSON", IGa).unwrap(), "이");
+		assert_eq!(select("iOS", IGa).unwrap(), "가");
+		assert_eq!(select("TV", EuRo).unwrap(), "로");
+		assert_eq!(select("PM", EuRo).unwrap(), "으로");
+		assert!(select("A", IGa).is_err());
+		assert!(select("Nolan", IGa).is_err());
+	}
+
+	#[test]
+	fn romanization() {
+		use josa::{Selector, EuRo, EunNeun, IGa};
+
+		let selector = Selector::new().romanization(true);
+		let names = [
+			("Yujin", "이", "으로"),
+			("Minseok", "이", "으로"),
+			("Seul", "이", "로"),
+			("Park", "이", "으로"),
+			("Kang", "이", "으로"),
+			("Jaeseob", "이", "으로"),
+			("Jiwoo", "가", "로"),
+			("Hyeonseo", "가", "로"),
+			("Lee", "가", "로"),
+			("KIM YUJIN", "이", "으로"),
+		];
+
+		for &(name, i_ga, eu_ro) in names.iter() {
+			assert_eq!(selector.select(name, IGa).unwrap(), i_ga, "{}", name);
+			assert_eq!(selector.select(name, EuRo).unwrap(), eu_ro, "{}", name);
+		}
+
+		let mut yujin = "Yujin".to_owned();
+		selector.push_josa(&mut yujin, EunNeun);
+
+		assert_eq!(yujin, "Yujin은");
+		assert!(selector.select("Lewis", IGa).is_err());
+		assert!(Selector::new().select("Yujin", IGa).is_err());
+	}
+
+	#[test]
+	fn fullwidth() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt, Selector};
+
+		assert_eq!(select("레벨３", IGa).unwrap(), "이");
+		assert_eq!(select("１０", EunNeun).unwrap(), "은");
+		assert_eq!(select("２", EuRo).unwrap(), "로");
+		assert_eq!(select("ＶＩＰ", IGa).unwrap(), "가");
+		assert_eq!(select("ＳＱＬ", EuRo).unwrap(), "로");
+		assert_eq!(select("ＡＢＣ", IGa).unwrap(), "가");
+		assert_eq!(select("고양이！", IGa).unwrap(), "가");
+		assert_eq!(select("서울시（가칭）", EunNeun).unwrap(), "는");
+		assert_eq!(select("ｊｏｓａ ＳＱＬ", EuRo).unwrap(), "로");
+		assert_eq!(select("ＳＱＬ （ｊｏｓａ ３）！", EuRo).unwrap(), "로");
+		assert!(select("ｊｏｓａ", IGa).is_err());
+
+		let selector = Selector::new().latin_heuristic(true);
+
+		assert_eq!(selector.select("ｇｏｏｇｌｅ", IGa).unwrap(), "이");
+		assert_eq!(selector.select("ｃｏｆｆｅｅ", IGa).unwrap(), "가");
+
+		let mut level = "레벨３".to_owned();
+		let mut cat = "고양이！".to_owned();
+
+		level.push_josa(IGa);
+		cat.push_josa(IGa);
+
+		assert_eq!(level, "레벨３이");
+		assert_eq!(cat, "고양이가！");
+	}
+
+	#[test]
+	fn mathematical_alphanumerics() {
+		use josa::{select, EulReul, EuRo, EunNeun, IGa, JosaExt, Selector};
+
+		// Bold
+		assert_eq!(select("𝐒𝐐𝐋", EulReul).unwrap(), "을");
+		assert_eq!(select("레벨 𝟑", IGa).unwrap(), "이");
+		// Italic
+		assert_eq!(select("𝑳𝒆𝒗𝒆𝒍 𝟑", IGa).unwrap(), "이");
+		assert_eq!(select("𝐴𝑃𝐼", EuRo).unwrap(), "로");
+		assert_eq!(select("𝑳𝒆𝒗𝒆𝒍 𝟑 (𝒋𝒐𝒔𝒂 𝟐)", IGa).unwrap(), "이");
+		// Double-struck
+		assert_eq!(select("𝕍𝕀ℙ", IGa).unwrap(), "가");
+		assert_eq!(select("𝟙𝟘", EunNeun).unwrap(), "은");
+		assert_eq!(select("𝟚", EuRo).unwrap(), "로");
+		assert!(select("𝕛𝕠𝕤𝕒", IGa).is_err());
+
+		let selector = Selector::new().latin_heuristic(true);
+
+		assert_eq!(selector.select("𝐆𝐨𝐨𝐠𝐥𝐞", IGa).unwrap(), "이");
+		assert_eq!(selector.select("𝑪𝒐𝒇𝒇𝒆𝒆", IGa).unwrap(), "가");
+		assert_eq!(selector.select("𝕔𝕙𝕒𝕥", EuRo).unwrap(), "으로");
+		assert_eq!(selector.select("𝒻𝒾𝓈𝒽", IGa).unwrap(), "가");
+
+		let mut level = "𝑳𝒆𝒗𝒆𝒍 𝟑".to_owned();
+		level.push_josa(IGa);
+
+		assert_eq!(level, "𝑳𝒆𝒗𝒆𝒍 𝟑이");
+	}
+}
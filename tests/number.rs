@@ -0,0 +1,317 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn digits() {
+		use josa::{select, EunNeun, EuRo, IGa, JosaExt};
+
+		let readings = [
+			('0', "이", "으로"),
+			('1', "이", "로"),
+			('2', "가", "로"),
+			('3', "이", "으로"),
+			('4', "가", "로"),
+			('5', "가", "로"),
+			('6', "이", "으로"),
+			('7', "이", "로"),
+			('8', "이", "로"),
+			('9', "가", "로"),
+		];
+
+		for &(digit, i_ga, eu_ro) in readings.iter() {
+			let noun = digit.to_string();
+
+			assert_eq!(select(&noun, IGa).unwrap(), i_ga);
+			assert_eq!(select(&noun, EuRo).unwrap(), eu_ro);
+		}
+
+		let mut level = "레벨 3".to_owned();
+		let mut cutline = "커트라인 2".to_owned();
+
+		level.push_josa(IGa);
+		cutline.push_josa(EunNeun);
+
+		assert_eq!(level, "레벨 3이");
+		assert_eq!(cutline, "커트라인 2는");
+	}
+
+	#[test]
+	fn numbers() {
+		use josa::{select, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("레벨 10", EunNeun).unwrap(), "은");
+		assert_eq!(select("11", EunNeun).unwrap(), "은");
+		assert_eq!(select("12", EunNeun).unwrap(), "는");
+		assert_eq!(select("100", IGa).unwrap(), "이");
+		assert_eq!(select("1000", IGa).unwrap(), "이");
+		assert_eq!(select("5000000", IGa).unwrap(), "이");
+		assert_eq!(select("100000000", IGa).unwrap(), "이");
+		assert_eq!(select("1000000000000", IGa).unwrap(), "가");
+		assert_eq!(select("10000000000000000", IGa).unwrap(), "이");
+		assert_eq!(select("0", IGa).unwrap(), "이");
+		assert_eq!(select("000", IGa).unwrap(), "이");
+		assert_eq!(select("007", IGa).unwrap(), "이");
+		assert!(select("100000000000000000000", IGa).is_err());
+
+		let mut huge = "100000000000000000000".to_owned();
+		huge.push_josa(IGa);
+
+		assert_eq!(huge, "100000000000000000000이(가)");
+	}
+
+	#[test]
+	fn decimals() {
+		use josa::{select, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("평점 4.5", IGa).unwrap(), "가");
+		assert_eq!(select("2.0", EunNeun).unwrap(), "은");
+		assert_eq!(select("버전 2.10", EunNeun).unwrap(), "은");
+		assert_eq!(select("3.14", EunNeun).unwrap(), "는");
+		assert_eq!(select("1.2.3", IGa).unwrap(), "이");
+		assert_eq!(select(".5", IGa).unwrap(), "가");
+
+		let mut rating = "평점 4.5".to_owned();
+		rating.push_josa(IGa);
+
+		assert_eq!(rating, "평점 4.5가");
+	}
+
+	#[test]
+	fn separators() {
+		use josa::{select, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("10,000", EunNeun).unwrap(), "은");
+		assert_eq!(select("1,000,000,000,000", IGa).unwrap(), "가");
+		assert_eq!(select("3,500", IGa).unwrap(), "이");
+		assert_eq!(select("3,505", IGa).unwrap(), "가");
+		assert_eq!(select("10\u{2009}000", EunNeun).unwrap(), "은");
+		assert_eq!(select("1,000\u{202F}000", IGa).unwrap(), "이");
+		assert_eq!(select("1,234.5", IGa).unwrap(), "가");
+		assert_eq!(select("1,000,", IGa).unwrap(), "이");
+		assert_eq!(select(",5", IGa).unwrap(), "가");
+
+		let mut comma = "1,000,".to_owned();
+		comma.push_josa(IGa);
+
+		assert_eq!(comma, "1,000이,");
+	}
+
+	#[test]
+	fn roman_numerals() {
+		use josa::{select, EuRo, IGa, JosaExt, Selector};
+
+		let numerals = [
+			("파이널판타지 Ⅶ", "이", "로"),
+			("Ⅱ", "가", "로"),
+			("Ⅲ", "이", "으로"),
+			("Ⅹ", "이", "으로"),
+			("Ⅻ", "가", "로"),
+			("ⅳ", "가", "로"),
+			("ⅩⅡ", "가", "로"),
+			("시즌 II", "가", "로"),
+			("시즌 III", "이", "으로"),
+			("Rocky IV", "가", "로"),
+			("Chapter XIV", "가", "로"),
+			("Part VIII", "이", "로"),
+			("Book XX", "이", "으로"),
+		];
+
+		for &(noun, i_ga, eu_ro) in numerals.iter() {
+			assert_eq!(select(noun, IGa).unwrap(), i_ga, "{}", noun);
+			assert_eq!(select(noun, EuRo).unwrap(), eu_ro, "{}", noun);
+		}
+
+		let mut seven = "Ⅶ".to_owned();
+		seven.push_josa(EuRo);
+
+		assert_eq!(seven, "Ⅶ로");
+		assert!(select("Mac OS X", IGa).is_err());
+		assert_eq!(select("IIX", IGa).unwrap(), "가");
+
+		let selector = Selector::new().single_roman_numerals(true);
+
+		assert_eq!(selector.select("Mac OS X", IGa).unwrap(), "이");
+		assert_eq!(selector.select("I", IGa).unwrap(), "이");
+		assert_eq!(selector.select("SX", EuRo).unwrap(), "로");
+	}
+
+	#[test]
+	fn other_scripts() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("٣", IGa).unwrap(), "이");
+		assert_eq!(select("३", IGa).unwrap(), "이");
+		assert_eq!(select("๓", IGa).unwrap(), "이");
+		assert_eq!(select("۲", EuRo).unwrap(), "로");
+		assert_eq!(select("৭", EuRo).unwrap(), "로");
+		assert_eq!(select("١٠", EunNeun).unwrap(), "은");
+		assert_eq!(select("𝟗", IGa).unwrap(), "가");
+		assert_eq!(select("٤.٥", IGa).unwrap(), "가");
+
+		let mut three = "٣".to_owned();
+		three.push_josa(IGa);
+
+		assert_eq!(three, "٣이");
+	}
+
+	#[test]
+	fn enclosed() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("①", EuRo).unwrap(), "로");
+		assert_eq!(select("②", IGa).unwrap(), "가");
+		assert_eq!(select("⑤", IGa).unwrap(), "가");
+		assert_eq!(select("⑩", EunNeun).unwrap(), "은");
+		assert_eq!(select("⑫", IGa).unwrap(), "가");
+		assert_eq!(select("⑳", EuRo).unwrap(), "으로");
+		assert_eq!(select("⑴", EuRo).unwrap(), "로");
+		assert_eq!(select("⒇", EunNeun).unwrap(), "은");
+		assert_eq!(select("⒊", IGa).unwrap(), "이");
+		assert_eq!(select("⓪", IGa).unwrap(), "이");
+		assert_eq!(select("⓫", IGa).unwrap(), "이");
+		assert_eq!(select("⓶", IGa).unwrap(), "가");
+		assert_eq!(select("㉑", EuRo).unwrap(), "로");
+		assert_eq!(select("㊿", EunNeun).unwrap(), "은");
+
+		let mut item = "항목 ②".to_owned();
+		let mut first = "①".to_owned();
+
+		item.push_josa(EunNeun);
+		first.push_josa(EuRo);
+
+		assert_eq!(item, "항목 ②는");
+		assert_eq!(first, "①로");
+		assert!(select("Ⓐ", IGa).is_err());
+	}
+
+	#[test]
+	fn keycaps() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("1\u{fe0f}\u{20e3}", EunNeun).unwrap(), "은");
+		assert_eq!(select("선택지 1\u{fe0f}\u{20e3}", EuRo).unwrap(), "로");
+		assert_eq!(select("2\u{fe0f}\u{20e3}", IGa).unwrap(), "가");
+		assert_eq!(select("3\u{20e3}", IGa).unwrap(), "이");
+		assert_eq!(select("1\u{fe0f}\u{20e3}0\u{fe0f}\u{20e3}", IGa).unwrap(), "이");
+		assert_eq!(select("5\u{fe0f}\u{20e3}!", IGa).unwrap(), "가");
+		assert!(select("#\u{fe0f}\u{20e3}", IGa).is_err());
+		assert!(select("*\u{fe0f}\u{20e3}", IGa).is_err());
+		assert!(select("\u{20e3}", IGa).is_err());
+
+		let mut one = "1\u{fe0f}\u{20e3}".to_owned();
+		let mut hash = "#\u{fe0f}\u{20e3}".to_owned();
+
+		one.push_josa(EunNeun);
+		hash.push_josa(EunNeun);
+
+		assert_eq!(one, "1\u{fe0f}\u{20e3}은");
+		assert_eq!(hash, "#\u{fe0f}\u{20e3}은(는)");
+	}
+
+	#[test]
+	fn scripts() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("10³", IGa).unwrap(), "이");
+		assert_eq!(select("x²", IGa).unwrap(), "가");
+		assert_eq!(select("x₂", IGa).unwrap(), "가");
+		assert_eq!(select("H₂O₁", EuRo).unwrap(), "로");
+		assert_eq!(select("10⁹", EunNeun).unwrap(), "는");
+		assert_eq!(select("2¹⁰", EunNeun).unwrap(), "은");
+		assert_eq!(select("10⁻³", IGa).unwrap(), "이");
+		assert_eq!(select("10²⁻", IGa).unwrap(), "가");
+		assert_eq!(select("10⁻", IGa).unwrap(), "이");
+		assert_eq!(select("5₊", IGa).unwrap(), "가");
+		assert_eq!(select("x⁽²⁾", IGa).unwrap(), "가");
+		assert_eq!(select("a₍₇₎", EuRo).unwrap(), "로");
+
+		let mut cubic = "10³".to_owned();
+		cubic.push_josa(IGa);
+
+		assert_eq!(cubic, "10³이");
+		assert!(select("⁻", IGa).is_err());
+	}
+
+	#[test]
+	fn units() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt, Selector};
+
+		let units = [
+			("3kg", "이", "으로"),
+			("3 kg", "이", "으로"),
+			("3KG", "이", "으로"),
+			("500g", "이", "으로"),
+			("50%", "가", "로"),
+			("36.5℃", "가", "로"),
+			("80°F", "가", "로"),
+			("10cm", "가", "로"),
+			("10㎝", "가", "로"),
+			("5㎏", "이", "으로"),
+			("100km/h", "가", "로"),
+			("2L", "가", "로"),
+			("3h", "이", "으로"),
+			("30min", "이", "으로"),
+			("16GB", "가", "로"),
+			("1920px", "이", "으로"),
+		];
+
+		for &(noun, i_ga, eu_ro) in units.iter() {
+			assert_eq!(select(noun, IGa).unwrap(), i_ga, "{}", noun);
+			assert_eq!(select(noun, EuRo).unwrap(), eu_ro, "{}", noun);
+		}
+
+		let mut weight = "3kg".to_owned();
+		weight.push_josa(EunNeun);
+
+		assert_eq!(weight, "3kg은");
+		assert!(select("5G", IGa).is_err());
+		assert!(select("3xyz", IGa).is_err());
+		assert!(select("kg", IGa).is_err());
+
+		let selector = Selector::new().unit("bpm", "비피엠").unit("g", "지");
+
+		assert_eq!(selector.select("120bpm", IGa).unwrap(), "이");
+		assert_eq!(selector.select("120BPM", IGa).unwrap(), "이");
+		assert_eq!(selector.select("500g", IGa).unwrap(), "가");
+		assert_eq!(selector.select("3kg", IGa).unwrap(), "이");
+
+		let selector = Selector::new().unit("kg", "").unit("bpm", "");
+
+		assert_eq!(selector.select("3kg", IGa).unwrap(), "이");
+		assert!(selector.select("120bpm", IGa).is_err());
+	}
+
+	#[test]
+	fn prices() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		let prices = [
+			("$5", "가", "로"),
+			("€10", "가", "로"),
+			("₩1,000", "이", "으로"),
+			("¥100", "이", "으로"),
+			("£3.50", "가", "로"),
+			("₽500", "이", "로"),
+			("USD 5", "가", "로"),
+			("KRW 1,000", "이", "으로"),
+			("5 USD", "가", "로"),
+			("5USD", "가", "로"),
+			("10$", "가", "로"),
+			("1,000₩", "이", "으로"),
+			("가격 $3", "가", "로"),
+		];
+
+		for &(noun, i_ga, eu_ro) in prices.iter() {
+			assert_eq!(select(noun, IGa).unwrap(), i_ga, "{}", noun);
+			assert_eq!(select(noun, EuRo).unwrap(), eu_ro, "{}", noun);
+		}
+
+		let mut yen = "¥100".to_owned();
+		yen.push_josa(EunNeun);
+
+		assert_eq!(yen, "¥100은");
+		assert_eq!(select("BUSD 3", IGa).unwrap(), "이");
+		assert_eq!(select("ABC 3", IGa).unwrap(), "이");
+		assert_eq!(select("¤3", IGa).unwrap(), "이");
+		assert!(select("$", IGa).is_err());
+	}
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn pronoun_contractions() {
+		use josa::{Selector, IGa, EunNeun};
+
+		let selector = Selector::new().pronoun_contractions(true);
+		let push = |noun: &str, josa| {
+			let mut noun = noun.to_owned();
+			selector.push_josa(&mut noun, josa);
+			noun
+		};
+
+		assert_eq!(push("나", IGa), "내가");
+		assert_eq!(push("저", IGa), "제가");
+		assert_eq!(push("너", IGa), "네가");
+		assert_eq!(push("누구", IGa), "누가");
+		assert_eq!(push("나", EunNeun), "나는");
+		assert_eq!(push("하나", IGa), "하나가");
+		assert_eq!(push("그리고 너", IGa), "그리고 너가");
+		assert_eq!(selector.select("나", IGa).unwrap(), "가");
+
+		let mut me = "나".to_owned();
+		let mut you = "너".to_owned();
+
+		selector.push_josa(&mut me, IGa.then("도"));
+		Selector::new().contractions(true).push_josa(&mut you, EunNeun.then("커녕"));
+
+		assert_eq!(me, "나가도");
+		assert_eq!(you, "너는커녕");
+	}
+
+	#[test]
+	fn pronoun_contractions_word_boundary() {
+		use josa::{Selector, IGa};
+
+		let selector = Selector::new().pronoun_contractions(true).word_boundary(true);
+		let push = |noun: &str| {
+			let mut noun = noun.to_owned();
+			selector.push_josa(&mut noun, IGa);
+			noun
+		};
+
+		assert_eq!(push("그리고 너"), "그리고 네가");
+		assert_eq!(push("바로\n저"), "바로\n제가");
+		assert_eq!(push("나"), "내가");
+		assert_eq!(push("하나"), "하나가");
+	}
+
+	#[test]
+	fn default_selector() {
+		use josa::{Selector, IGa};
+
+		let mut me = "나".to_owned();
+		Selector::new().push_josa(&mut me, IGa);
+
+		assert_eq!(me, "나가");
+	}
+
+	#[test]
+	fn contractions() {
+		use josa::{Selector, EunNeun, EulReul, IGa, GwaWa};
+
+		let selector = Selector::new().contractions(true);
+		let push = |noun: &str, josa| {
+			let mut noun = noun.to_owned();
+			selector.push_josa(&mut noun, josa);
+			noun
+		};
+
+		assert_eq!(push("나", EunNeun), "난");
+		assert_eq!(push("나", EulReul), "날");
+		assert_eq!(push("너", EunNeun), "넌");
+		assert_eq!(push("저", EunNeun), "전");
+		assert_eq!(push("것", EunNeun), "건");
+		assert_eq!(push("것", IGa), "게");
+		assert_eq!(push("그리고 나", EunNeun), "그리고 난");
+		assert_eq!(push("하나", EunNeun), "하나는");
+		assert_eq!(push("나", GwaWa), "나와");
+		assert_eq!(push("나", IGa), "나가");
+
+		let mut formal = "나".to_owned();
+		Selector::new().push_josa(&mut formal, EunNeun);
+
+		assert_eq!(formal, "나는");
+	}
+}
@@ -0,0 +1,384 @@
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn whitespace() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt, Do};
+
+		assert_eq!(select("고양이 ", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\n", IGa).unwrap(), "가");
+		assert_eq!(select("사람\t", EunNeun).unwrap(), "은");
+		assert_eq!(select("사람\r\n", EunNeun).unwrap(), "은");
+		assert_eq!(select("레벨 3 ", IGa).unwrap(), "이");
+
+		let mut cat = "고양이 ".to_owned();
+		cat.push_josa(IGa);
+
+		assert_eq!(cat, "고양이 가");
+
+		let mut blank = " \n".to_owned();
+		blank.push_josa(IGa);
+
+		assert_eq!(blank, " \n");
+		assert!(matches!(select(" \t", IGa), Err(Error::EmptyStr)));
+		assert!(matches!(select(" ", Do), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn punctuation() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt, Placement, Selector};
+
+		assert_eq!(select("고양이.", IGa).unwrap(), "가");
+		assert_eq!(select("고양이...", IGa).unwrap(), "가");
+		assert_eq!(select("뭐라고?", EunNeun).unwrap(), "는");
+		assert_eq!(select("대박!", EunNeun).unwrap(), "은");
+		assert_eq!(select("사람…", IGa).unwrap(), "이");
+		assert_eq!(select("사람~", IGa).unwrap(), "이");
+		assert_eq!(select("레벨 3.", IGa).unwrap(), "이");
+
+		let mut cat = "고양이...".to_owned();
+		let mut person = "사람, ".to_owned();
+		let mut after = "고양이?!".to_owned();
+
+		cat.push_josa(IGa);
+		person.push_josa(EunNeun);
+		Selector::new().punctuation(Placement::After).push_josa(&mut after, IGa);
+
+		assert_eq!(cat, "고양이가...");
+		assert_eq!(person, "사람은, ");
+		assert_eq!(after, "고양이?!가");
+
+		let mut exclaimed = "《고양이!》".to_owned();
+		let mut acronym = "책 (NEW!)".to_owned();
+		let mut quoted = "책 '신간!'".to_owned();
+		let mut sentence = "《고양이?》.".to_owned();
+
+		exclaimed.push_josa(IGa);
+		acronym.push_josa(IGa);
+		quoted.push_josa(IGa);
+		sentence.push_josa(IGa);
+
+		assert_eq!(exclaimed, "《고양이!》가");
+		assert_eq!(acronym, "책 (NEW!)이");
+		assert_eq!(quoted, "책 '신간!'이");
+		assert_eq!(sentence, "《고양이?》가.");
+
+		let mut dots = "...".to_owned();
+		dots.push_josa(IGa);
+
+		assert_eq!(dots, "...");
+		assert!(matches!(select("?!", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn symbols() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt, Placement, Selector};
+
+		assert_eq!(select("갤럭시™", EunNeun).unwrap(), "는");
+		assert_eq!(select("윈도우®", IGa).unwrap(), "가");
+		assert_eq!(select("저작권©", IGa).unwrap(), "이");
+		assert_eq!(select("주의※", IGa).unwrap(), "가");
+		assert_eq!(select("사망*", EunNeun).unwrap(), "은");
+		assert_eq!(select("사망†‡", EunNeun).unwrap(), "은");
+
+		let mut galaxy = "갤럭시™".to_owned();
+		let mut footnote = "사망*.".to_owned();
+		let mut before = "윈도우®".to_owned();
+
+		galaxy.push_josa(EunNeun);
+		footnote.push_josa(EunNeun);
+		Selector::new().symbols(Placement::Before).push_josa(&mut before, IGa);
+
+		assert_eq!(galaxy, "갤럭시™는");
+		assert_eq!(footnote, "사망*은.");
+		assert_eq!(before, "윈도우가®");
+
+		let mut symbols = "™®".to_owned();
+		symbols.push_josa(IGa);
+
+		assert_eq!(symbols, "™®");
+		assert!(matches!(select("*", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn closing_marks() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("《홍길동전》", EunNeun).unwrap(), "은");
+		assert_eq!(select("'불꽃'", IGa).unwrap(), "이");
+		assert_eq!(select("‘불꽃’", IGa).unwrap(), "이");
+		assert_eq!(select("“고양이”", IGa).unwrap(), "가");
+		assert_eq!(select("「사과」", IGa).unwrap(), "가");
+		assert_eq!(select("『토지』", EunNeun).unwrap(), "는");
+		assert_eq!(select("〈별〉", IGa).unwrap(), "이");
+		assert_eq!(select("(고양이)", IGa).unwrap(), "가");
+		assert_eq!(select("[사과]", IGa).unwrap(), "가");
+		assert_eq!(select("{책}", IGa).unwrap(), "이");
+		assert_eq!(select("（책）", IGa).unwrap(), "이");
+		assert_eq!(select("\"'책'\"", IGa).unwrap(), "이");
+
+		let mut book = "《홍길동전》".to_owned();
+		let mut sentence = "《홍길동전》.".to_owned();
+
+		book.push_josa(EunNeun);
+		sentence.push_josa(EunNeun);
+
+		assert_eq!(book, "《홍길동전》은");
+		assert_eq!(sentence, "《홍길동전》은.");
+
+		let mut question = "「어디 가?」".to_owned();
+		let mut nested = "\"'사과.'\"".to_owned();
+		let mut bracketed = "[사과!] ".to_owned();
+
+		question.push_josa(EunNeun);
+		nested.push_josa(IGa);
+		bracketed.push_josa(IGa);
+
+		assert_eq!(select("「어디 가?」", EunNeun).unwrap(), "는");
+		assert_eq!(question, "「어디 가?」는");
+		assert_eq!(nested, "\"'사과.'\"가");
+		assert_eq!(bracketed, "[사과!] 가");
+		assert!(matches!(select("\")", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn parenthetical() {
+		use josa::{select, EunNeun, IGa, JosaExt, Selector};
+
+		assert_eq!(select("한화(주)", EunNeun).unwrap(), "는");
+		assert_eq!(select("서울시(가칭)", EunNeun).unwrap(), "는");
+		assert_eq!(select("서울시 (가칭)", EunNeun).unwrap(), "는");
+		assert_eq!(select("책(book)", IGa).unwrap(), "이");
+		assert_eq!(select("책(f(x))", IGa).unwrap(), "이");
+		assert_eq!(select("책(가)(나)", IGa).unwrap(), "이");
+		assert_eq!(select("(고양이)", IGa).unwrap(), "가");
+		assert_eq!(select("'서울시(가칭)'", EunNeun).unwrap(), "는");
+		assert_eq!(select("책 (NEW!)", IGa).unwrap(), "이");
+		assert_eq!(select("고양이 (new.)", IGa).unwrap(), "가");
+		assert_eq!(select("책 (신간?)", IGa).unwrap(), "이");
+
+		let selector = Selector::new().parenthetical_contents(true);
+
+		assert_eq!(selector.select("서울시(가칭)", EunNeun).unwrap(), "은");
+		assert_eq!(selector.select("서울시()", EunNeun).unwrap(), "는");
+		assert_eq!(selector.select("책 (사과?)", IGa).unwrap(), "가");
+		assert_eq!(selector.select("책 (!)", IGa).unwrap(), "이");
+		assert_eq!(selector.select("책(사과(배))", IGa).unwrap(), "가");
+		assert_eq!(selector.select("책(사과(()))", IGa).unwrap(), "가");
+
+		let nested = format!("책{}사과{}", "(".repeat(100_000), ")".repeat(100_000));
+
+		assert_eq!(select(&nested, IGa).unwrap(), "이");
+		assert_eq!(selector.select(&nested, IGa).unwrap(), "가");
+
+		let mut city = "서울시(가칭).".to_owned();
+		city.push_josa(EunNeun);
+
+		assert_eq!(city, "서울시(가칭)는.");
+
+		assert_eq!(select("서울시가칭)", EunNeun).unwrap(), "은");
+		assert_eq!(select("서울시(가칭", EunNeun).unwrap(), "은");
+		assert_eq!(select("서울시(가칭))", EunNeun).unwrap(), "은");
+	}
+
+	#[test]
+	fn emoji() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt, Placement, Selector};
+
+		assert_eq!(select("고양이🐱", IGa).unwrap(), "가");
+		assert_eq!(select("축하🎉🎉", EunNeun).unwrap(), "는");
+		assert_eq!(select("가족👨\u{200d}👩\u{200d}👧", IGa).unwrap(), "이");
+		assert_eq!(select("최고👍🏽", IGa).unwrap(), "가");
+		assert_eq!(select("사랑❤\u{fe0f}", IGa).unwrap(), "이");
+		assert_eq!(select("한국🇰🇷", IGa).unwrap(), "이");
+		assert_eq!(select("별⭐!", IGa).unwrap(), "이");
+
+		let mut cat = "고양이🐱".to_owned();
+		let mut before = "축하🎉🎉".to_owned();
+
+		cat.push_josa(IGa);
+		Selector::new().emoji(Placement::Before).push_josa(&mut before, EunNeun);
+
+		assert_eq!(cat, "고양이🐱가");
+		assert_eq!(before, "축하는🎉🎉");
+
+		let mut emoji = "🐱".to_owned();
+		emoji.push_josa(IGa);
+
+		assert_eq!(emoji, "🐱");
+		assert!(matches!(select("🎉", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn invisible() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt, Placement, Selector};
+
+		assert_eq!(select("고양이\u{200b}", IGa).unwrap(), "가");
+		assert_eq!(select("사람\u{200d}", EunNeun).unwrap(), "은");
+		assert_eq!(select("사람\u{fe0f}", EunNeun).unwrap(), "은");
+		assert_eq!(select("고양이\u{feff}", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\u{2060}\u{200c}", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\u{200b}.", IGa).unwrap(), "가");
+
+		let mut cat = "고양이\u{feff}".to_owned();
+		let mut heart = "사랑❤\u{fe0f}".to_owned();
+
+		cat.push_josa(IGa);
+		Selector::new().emoji(Placement::Before).push_josa(&mut heart, IGa);
+
+		assert_eq!(cat, "고양이\u{feff}가");
+		assert_eq!(heart, "사랑이❤\u{fe0f}");
+
+		let mut invisible = "\u{200b}\u{feff}".to_owned();
+		invisible.push_josa(IGa);
+
+		assert_eq!(invisible, "\u{200b}\u{feff}");
+		assert!(matches!(select("\u{200b}", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn unicode_spaces() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("고양이\u{a0}", IGa).unwrap(), "가");
+		assert_eq!(select("사람\u{3000}", EunNeun).unwrap(), "은");
+		assert_eq!(select("사람\u{2003}", EunNeun).unwrap(), "은");
+		assert_eq!(select("고양이\u{ad}", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\u{206f}", IGa).unwrap(), "가");
+		assert_eq!(select("레벨 3\u{202f}", IGa).unwrap(), "이");
+		assert_eq!(select("고양이\u{a0}\u{ad}\u{3000}", IGa).unwrap(), "가");
+
+		let mut cat = "고양이\u{a0}".to_owned();
+		let mut person = "사람\u{3000}".to_owned();
+		let mut hyphen = "고양이\u{ad}".to_owned();
+
+		cat.push_josa(IGa);
+		person.push_josa(EunNeun);
+		hyphen.push_josa(IGa);
+
+		assert_eq!(cat, "고양이\u{a0}가");
+		assert_eq!(person, "사람\u{3000}은");
+		assert_eq!(hyphen, "고양이\u{ad}가");
+
+		let mut blank = "\u{a0}\u{3000}".to_owned();
+		blank.push_josa(IGa);
+
+		assert_eq!(blank, "\u{a0}\u{3000}");
+		assert!(matches!(select("\u{a0}\u{ad}", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn bidi_controls() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("고양이\u{200e}", IGa).unwrap(), "가");
+		assert_eq!(select("사람\u{200f}", EunNeun).unwrap(), "은");
+		assert_eq!(select("\u{202b}사람\u{202c}", EunNeun).unwrap(), "은");
+		assert_eq!(select("\u{2068}고양이\u{2069}", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\u{61c}", IGa).unwrap(), "가");
+
+		let mut cat = "\u{2068}고양이\u{2069}".to_owned();
+		let mut person = "\u{2067}사람\u{2069}\u{200e}".to_owned();
+
+		cat.push_josa(IGa);
+		person.push_josa(EunNeun);
+
+		assert_eq!(cat, "\u{2068}고양이\u{2069}가");
+		assert_eq!(person, "\u{2067}사람\u{2069}\u{200e}은");
+		assert!(matches!(select("\u{2068}\u{2069}", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn ansi_escapes() {
+		use josa::{select, Error, EunNeun, IGa, JosaExt};
+
+		assert_eq!(select("\x1b[1m고양이\x1b[0m", IGa).unwrap(), "가");
+		assert_eq!(select("\x1b[31m사람\x1b[39m", EunNeun).unwrap(), "은");
+		assert_eq!(select("\x1b[1;38;5;208m사람\x1b[0m\x1b[K", EunNeun).unwrap(), "은");
+		assert_eq!(select("\x1b[1m고양이\x1b[0m.", IGa).unwrap(), "가");
+		assert_eq!(select("\x1b[1m고양이.\x1b[0m", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\x1b[0", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\x1b[", IGa).unwrap(), "가");
+		assert_eq!(select("고양이\x1b", IGa).unwrap(), "가");
+
+		let mut cat = "\x1b[1m고양이\x1b[0m".to_owned();
+		let mut person = "\x1b[32m사람\x1b[0m.".to_owned();
+		let mut truncated = "고양이\x1b[0".to_owned();
+
+		cat.push_josa(IGa);
+		person.push_josa(EunNeun);
+		truncated.push_josa(IGa);
+
+		assert_eq!(cat, "\x1b[1m고양이\x1b[0m가");
+		assert_eq!(person, "\x1b[32m사람\x1b[0m은.");
+		assert_eq!(truncated, "고양이\x1b[0가");
+
+		assert!(matches!(select("\x1b[0m", IGa), Err(Error::EmptyStr)));
+	}
+
+	#[test]
+	fn markdown() {
+		use josa::{Eu, EunNeun, IGa, Selector};
+
+		let selector = Selector::new().markdown(true);
+
+		assert_eq!(selector.select("**칼**", Eu).unwrap(), "");
+		assert_eq!(selector.select("**손**", Eu).unwrap(), "으");
+		assert_eq!(selector.select("_고양이_", IGa).unwrap(), "가");
+		assert_eq!(selector.select("`변수`", IGa).unwrap(), "가");
+		assert_eq!(selector.select("~~취소~~", EunNeun).unwrap(), "는");
+		assert_eq!(selector.select("**_고양이_**", IGa).unwrap(), "가");
+		assert_eq!(selector.select("이건 **책**.", IGa).unwrap(), "이");
+
+		let mut cancel = "~~취소~~".to_owned();
+		let mut book = "**책**.".to_owned();
+		let mut code = "`변수`".to_owned();
+
+		selector.push_josa(&mut cancel, EunNeun);
+		selector.push_josa(&mut book, IGa);
+		selector.push_josa(&mut code, IGa);
+
+		assert_eq!(cancel, "~~취소~~는");
+		assert_eq!(book, "**책**이.");
+		assert_eq!(code, "`변수`가");
+
+		assert_eq!(selector.select("`변수", IGa).unwrap(), "가");
+		assert!(selector.select("변수`", IGa).is_err());
+		assert!(selector.select("``", IGa).is_err());
+		assert!(Selector::new().select("`변수`", IGa).is_err());
+
+		let mut unbalanced = "~~취소".to_owned();
+		selector.push_josa(&mut unbalanced, EunNeun);
+
+		assert_eq!(unbalanced, "~~취소는");
+	}
+
+	#[test]
+	fn last_hangul() {
+		use josa::{select_last_hangul, Do, Error, EunNeun, IGa, Selector};
+
+		assert_eq!(select_last_hangul("고양이 (NEW!)", IGa).unwrap(), "가");
+		assert_eq!(select_last_hangul("사과🍎", IGa).unwrap(), "가");
+		assert_eq!(select_last_hangul("결과값)", EunNeun).unwrap(), "은");
+		assert_eq!(select_last_hangul("책 #1", IGa).unwrap(), "이");
+		assert_eq!(select_last_hangul("새 iPhone", IGa).unwrap(), "가");
+		assert_eq!(select_last_hangul("고양이 :)", Do).unwrap(), "도");
+
+		let selector = Selector::new().last_hangul(true);
+
+		let mut cat = "고양이 (NEW!)".to_owned();
+		let mut result = "결과값)".to_owned();
+		let mut dots = "고양이...".to_owned();
+
+		selector.push_josa(&mut cat, IGa);
+		selector.push_josa(&mut result, EunNeun);
+		selector.push_josa(&mut dots, IGa);
+
+		assert_eq!(cat, "고양이 (NEW!)가");
+		assert_eq!(result, "결과값)은");
+		assert_eq!(dots, "고양이가...");
+
+		assert!(matches!(select_last_hangul("", IGa), Err(Error::EmptyStr)));
+		assert!(matches!(select_last_hangul("(!)", IGa), Err(Error::ParseSyllable('('))));
+		assert!(matches!(select_last_hangul("iPhone", IGa), Err(Error::ParseSyllable('e'))));
+	}
+}
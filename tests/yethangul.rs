@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "yethangul")]
+	#[test]
+	fn yethangul() {
+		use josa::{select, EuRo, EunNeun, IGa, JosaExt};
+
+		let han = "\u{1112}\u{119e}\u{11ab}"; // ᄒᆞᆫ
+		let haru = "\u{1112}\u{119e}\u{1105}\u{119e}"; // ᄒᆞᄅᆞ
+		let pansios = "\u{1140}\u{1161}\u{11eb}"; // ᅀᅡᇫ
+		let rieul = "\u{1100}\u{1161}\u{11d9}"; // 가 with ㄹㆆ
+		let cluster = "\u{1100}\u{1161}\u{11af}\u{11a8}"; // 가 with ㄹ and ㄱ
+		let extended = "\u{a960}\u{d7b0}\u{d7cb}";
+
+		assert_eq!(select(han, IGa).unwrap(), "이");
+		assert_eq!(select(haru, IGa).unwrap(), "가");
+		assert_eq!(select(pansios, EunNeun).unwrap(), "은");
+		assert_eq!(select(rieul, EuRo).unwrap(), "로");
+		assert_eq!(select(cluster, EuRo).unwrap(), "으로");
+		assert_eq!(select(extended, EuRo).unwrap(), "으로");
+		assert_eq!(select("나랏\u{1106}\u{1161}\u{11af}\u{110a}\u{119e}\u{11b7}", IGa).unwrap(), "이");
+		assert_eq!(select("가\u{11eb}", IGa).unwrap(), "이");
+		assert_eq!(select("가\u{11af}\u{11d9}", EuRo).unwrap(), "로");
+
+		let clusters = [
+			("\u{11cc}", "으로"), // ㄹㄱㅅ
+			("\u{11d1}", "으로"), // ㄹㅁㄱ
+			("\u{11d3}", "으로"), // ㄹㅂㅅ
+			("\u{11d8}", "으로"), // ㄹㅋ
+			("\u{d7d5}", "으로"), // ㄹㄱㄱ
+			("\u{d7d9}", "으로"), // ㄹㅂㄷ
+			("\u{11cd}", "로"), // ㄹㄴ
+			("\u{11d5}", "로"), // ㄹㅸ
+			("\u{11d7}", "로"), // ㄹㅿ
+			("\u{d7dc}", "로"), // ㄹㆆㅎ
+		];
+
+		for &(jongseong, eu_ro) in clusters.iter() {
+			assert_eq!(select(&format!("\u{1100}\u{1161}{}", jongseong), EuRo).unwrap(), eu_ro, "{}", jongseong);
+		}
+
+		let mut noun = han.to_owned();
+		noun.push_josa(EunNeun);
+
+		assert_eq!(noun, "\u{1112}\u{119e}\u{11ab}은");
+		assert_eq!(select("\u{119e}", IGa).unwrap(), "가");
+		assert_eq!(select("\u{11d9}", EuRo).unwrap(), "로");
+		assert!(select("\u{1140}", IGa).is_err());
+	}
+
+	#[cfg(not(feature = "yethangul"))]
+	#[test]
+	fn yethangul() {
+		use josa::{select, IGa};
+
+		assert!(select("\u{1112}\u{119e}\u{1105}\u{119e}", IGa).is_err());
+	}
+}